@@ -0,0 +1,63 @@
+//! Types dealing with bangumis.
+
+use serde::Deserialize;
+
+/// Identifies a bangumi, either by its season or one of its episodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BangumiId {
+    /// A season ID, like `ss12345`.
+    Season(u64),
+    /// An episode ID, like `ep12345`.
+    Episode(u64),
+}
+
+/// Season info, returned by `/pgc/view/web/season`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Season {
+    /// The ID of the season.
+    pub season_id: u64,
+    /// The title of the season.
+    pub title: String,
+    /// The description of the season.
+    pub evaluate: String,
+    /// The URL of the cover.
+    pub cover: String,
+    /// The rating of the season, if it has enough ratings.
+    pub rating: Option<Rating>,
+    /// The episodes of the season.
+    #[serde(default)]
+    pub episodes: Vec<Episode>,
+    /// The latest episode of the season.
+    pub new_ep: Option<NewEpisode>,
+}
+
+/// The rating of a season.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Rating {
+    /// The score, out of 10.
+    pub score: f64,
+    /// How many users have rated.
+    pub count: u64,
+}
+
+/// An episode of a season.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Episode {
+    /// The ID of the episode.
+    pub id: u64,
+    /// The short title of the episode, usually the episode number.
+    pub title: String,
+    /// The full title of the episode.
+    pub long_title: String,
+}
+
+/// The latest episode of a season.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct NewEpisode {
+    /// Describes the progress of the season, like `已完结, 全12话`.
+    pub desc: String,
+}
@@ -0,0 +1,264 @@
+//! BiliBili web API.
+//!
+//! See <https://github.com/SocialSisterYi/bilibili-API-collect> for the documentation of the endpoints.
+pub mod bangumi;
+pub mod live;
+pub mod user;
+pub mod video;
+mod wbi;
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+use bytes::Bytes;
+use futures_core::future::BoxFuture;
+use http_body_util::BodyExt;
+use serde::{Deserialize, de::DeserializeOwned};
+use tower::{BoxError, Service, ServiceExt, buffer::Buffer, util::BoxService};
+
+use crate::bangumi::{BangumiId, Season};
+use crate::live::RoomInfo;
+use crate::user::{SpaceVideo, SpaceVideos, UserCard};
+use crate::video::{Tag, VideoView};
+use crate::wbi::MixinKey;
+
+type BoxBody = http_body_util::combinators::BoxBody<Bytes, crate::Error>;
+
+pub type Result<T> = std::result::Result<T, crate::Error>;
+
+pub type BiliBiliService =
+    Buffer<http::Request<BoxBody>, BoxFuture<'static, Result<http::Response<BoxBody>>>>;
+
+static USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
+
+/// How long WBI keys are kept before fetching again.
+///
+/// Keys rotate daily at no fixed time, so they are fetched hourly
+/// to stop using stale keys soon after a rotation.
+const WBI_KEY_TTL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Clone)]
+pub struct BiliBiliClient {
+    base_url: http::Uri,
+    live_base_url: http::Uri,
+    service: BiliBiliService,
+    wbi_key: Arc<Mutex<Option<(MixinKey, Instant)>>>,
+}
+
+impl BiliBiliClient {
+    pub fn new<S>(service: S, base_url: http::Uri, live_base_url: http::Uri) -> Self
+    where
+        S: tower::Service<http::Request<BoxBody>, Response = http::Response<BoxBody>>
+            + Send
+            + 'static,
+        S::Future: Send + 'static,
+        S::Error: Into<BoxError>,
+    {
+        let service = Buffer::new(
+            BoxService::new(service.map_err(|e| Error::Service(e.into()))),
+            1024,
+        );
+
+        Self {
+            service,
+            base_url,
+            live_base_url,
+            wbi_key: Default::default(),
+        }
+    }
+
+    /// Get the info of a video.
+    pub async fn video_view(&self, bvid: &str) -> crate::Result<VideoView> {
+        let query = self
+            .wbi_key()
+            .await?
+            .sign(&[("bvid", bvid.to_string())], unix_timestamp());
+
+        self.get(
+            &self.base_url,
+            &format!("/x/web-interface/wbi/view?{query}"),
+        )
+        .await
+        .and_then(Envelope::into_data)
+    }
+
+    /// Get the tags of a video.
+    pub async fn video_tags(&self, bvid: &str) -> crate::Result<Vec<Tag>> {
+        self.get(&self.base_url, &format!("/x/tag/archive/tags?bvid={bvid}"))
+            .await
+            .and_then(Envelope::into_data)
+    }
+
+    /// Get the card of an user, including the follower count.
+    pub async fn user_card(&self, mid: u64) -> crate::Result<UserCard> {
+        self.get(&self.base_url, &format!("/x/web-interface/card?mid={mid}"))
+            .await
+            .and_then(Envelope::into_data)
+    }
+
+    /// Get the latest videos uploaded by an user.
+    pub async fn user_videos(&self, mid: u64, count: u32) -> crate::Result<Vec<SpaceVideo>> {
+        let query = self.wbi_key().await?.sign(
+            &[
+                ("mid", mid.to_string()),
+                ("ps", count.to_string()),
+                ("pn", "1".to_string()),
+                ("order", "pubdate".to_string()),
+            ],
+            unix_timestamp(),
+        );
+
+        self.get(&self.base_url, &format!("/x/space/wbi/arc/search?{query}"))
+            .await
+            .and_then(Envelope::into_data)
+            .map(|videos: SpaceVideos| videos.list.vlist)
+    }
+
+    /// Get the info of a bangumi season.
+    pub async fn bangumi_season(&self, id: BangumiId) -> crate::Result<Season> {
+        let query = match id {
+            BangumiId::Season(season_id) => format!("season_id={season_id}"),
+            BangumiId::Episode(ep_id) => format!("ep_id={ep_id}"),
+        };
+
+        self.get(&self.base_url, &format!("/pgc/view/web/season?{query}"))
+            .await
+            .and_then(Envelope::into_data)
+    }
+
+    /// Get the info of a live room.
+    pub async fn live_room(&self, room_id: u64) -> crate::Result<RoomInfo> {
+        self.get(
+            &self.live_base_url,
+            &format!("/room/v1/Room/get_info?room_id={room_id}"),
+        )
+        .await
+        .and_then(Envelope::into_data)
+    }
+
+    /// Returns the cached WBI mixin key, fetching it again if it's expired.
+    async fn wbi_key(&self) -> crate::Result<MixinKey> {
+        if let Some((key, fetched)) = &*self.wbi_key.lock().unwrap_or_else(PoisonError::into_inner)
+            && fetched.elapsed() < WBI_KEY_TTL
+        {
+            return Ok(key.clone());
+        }
+
+        // The endpoint reports an error when not logged in, but still returns the keys.
+        let nav: Envelope<Nav> = self.get(&self.base_url, "/x/web-interface/nav").await?;
+        let Some(Nav { wbi_img }) = nav.data else {
+            return Err(Error::ServerError {
+                code: nav.code,
+                message: nav.message,
+            });
+        };
+        let key = MixinKey::new(
+            MixinKey::key_from_url(&wbi_img.img_url),
+            MixinKey::key_from_url(&wbi_img.sub_url),
+        );
+
+        self.wbi_key
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace((key.clone(), Instant::now()));
+
+        Ok(key)
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        base_url: &http::Uri,
+        path_and_query: &str,
+    ) -> crate::Result<T> {
+        use http::header::{REFERER, USER_AGENT};
+
+        let builder = http::uri::Builder::from(base_url.clone());
+        let uri = builder
+            .path_and_query(path_and_query)
+            .build()
+            .map_err(Error::Http)?;
+
+        let request = http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header(REFERER, "https://www.bilibili.com/")
+            .header(USER_AGENT, crate::USER_AGENT)
+            .body(
+                http_body_util::Empty::<Bytes>::new()
+                    .map_err(|e| Error::Other(Box::new(e) as BoxError))
+                    .boxed(),
+            )
+            .map_err(Error::Http)?;
+        let mut service = self.service.clone();
+        let response = service
+            .ready()
+            .await
+            .map_err(Error::Service)?
+            .call(request)
+            .await
+            .map_err(Error::Service)?;
+
+        let body = response.into_body();
+        let bytes = body.collect().await?.to_bytes();
+        let json = String::from_utf8(bytes.to_vec()).map_err(Error::InvalidUtf8)?;
+        let de = &mut serde_json::Deserializer::from_str(&json);
+
+        serde_path_to_error::deserialize(de).map_err(Error::Json)
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// The common response of BiliBili web APIs.
+#[derive(Deserialize)]
+struct Envelope<T> {
+    code: i64,
+    message: String,
+    /// PGC endpoints use `result` instead.
+    #[serde(alias = "result")]
+    data: Option<T>,
+}
+
+impl<T> Envelope<T> {
+    fn into_data(self) -> crate::Result<T> {
+        match (self.code, self.data) {
+            (0, Some(data)) => Ok(data),
+            (code, _) => Err(Error::ServerError {
+                code,
+                message: self.message,
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Nav {
+    wbi_img: WbiImg,
+}
+
+#[derive(Deserialize)]
+struct WbiImg {
+    img_url: String,
+    sub_url: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("HTTP Error")]
+    Http(#[source] http::Error),
+    #[error("Failed to convert data into response as it's not in UTF-8")]
+    InvalidUtf8(#[source] std::string::FromUtf8Error),
+    #[error("Invalid JSON")]
+    Json(#[source] serde_path_to_error::Error<serde_json::Error>),
+    #[error("Error while queuing client for request")]
+    Service(#[source] BoxError),
+    #[error("BiliBili returned error {code}: {message}")]
+    ServerError { code: i64, message: String },
+    #[error(transparent)]
+    Other(#[from] BoxError),
+}
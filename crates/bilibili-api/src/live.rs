@@ -0,0 +1,52 @@
+//! Types dealing with live rooms.
+
+use serde::Deserialize;
+
+/// Live room info, returned by `/room/v1/Room/get_info`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct RoomInfo {
+    /// The ID of the room.
+    pub room_id: u64,
+    /// The User ID of the streamer.
+    pub uid: u64,
+    /// The title of the room.
+    pub title: String,
+    /// The status of the room.
+    pub live_status: LiveStatus,
+    /// How many viewers the room have.
+    pub online: u64,
+    /// The area of the room.
+    pub area_name: String,
+    /// The URL of the cover.
+    pub user_cover: String,
+}
+
+/// The status of a live room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum LiveStatus {
+    Offline = 0,
+    Live = 1,
+    /// Playing recorded videos in rotation.
+    Rotating = 2,
+}
+
+impl<'de> Deserialize<'de> for LiveStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u8::deserialize(deserializer)?;
+        match value {
+            0 => Ok(Self::Offline),
+            1 => Ok(Self::Live),
+            2 => Ok(Self::Rotating),
+            _ => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(value.into()),
+                &"0, 1 or 2",
+            )),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Types dealing with users.
+
+use serde::Deserialize;
+
+/// User card, returned by `/x/web-interface/card`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct UserCard {
+    /// The basic info of the user.
+    pub card: Card,
+    /// How many followers the user have.
+    pub follower: u64,
+    /// How many videos the user have uploaded.
+    pub archive_count: u64,
+}
+
+/// The basic info of an user.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Card {
+    /// The name of the user.
+    pub name: String,
+    /// The URL of the user's avatar.
+    pub face: String,
+    /// The signature of the user.
+    pub sign: String,
+}
+
+/// A video uploaded by an user, returned by `/x/space/wbi/arc/search`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct SpaceVideo {
+    /// The AV ID of the video.
+    pub aid: u64,
+    /// The BV ID of the video.
+    pub bvid: String,
+    /// The title of the video.
+    pub title: String,
+    /// The Unix timestamp the video is created.
+    pub created: i64,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SpaceVideos {
+    pub(crate) list: SpaceVideoList,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SpaceVideoList {
+    pub(crate) vlist: Vec<SpaceVideo>,
+}
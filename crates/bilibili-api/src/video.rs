@@ -0,0 +1,63 @@
+//! Types dealing with videos.
+
+use serde::Deserialize;
+
+/// Video info, returned by `/x/web-interface/wbi/view`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct VideoView {
+    /// The AV ID of the video.
+    pub aid: u64,
+    /// The BV ID of the video.
+    pub bvid: String,
+    /// The title of the video.
+    pub title: String,
+    /// The description of the video, `-` if it's empty.
+    pub desc: String,
+    /// The URL of the cover.
+    pub pic: String,
+    /// The Unix timestamp the video is published.
+    pub pubdate: i64,
+    /// The duration of the video in seconds.
+    pub duration: u64,
+    /// The uploader of the video.
+    pub owner: Owner,
+    /// The statistics of the video.
+    pub stat: Stat,
+}
+
+/// The uploader of a video.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Owner {
+    /// The User ID of the uploader.
+    pub mid: u64,
+    /// The name of the uploader.
+    pub name: String,
+    /// The URL of the uploader's avatar.
+    pub face: String,
+}
+
+/// The statistics of a video.
+#[derive(Deserialize, Debug, Clone)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub struct Stat {
+    pub view: u64,
+    pub danmaku: u64,
+    pub reply: u64,
+    pub favorite: u64,
+    pub coin: u64,
+    pub share: u64,
+    pub like: u64,
+}
+
+/// A tag of a video, returned by `/x/tag/archive/tags`.
+#[derive(Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct Tag {
+    /// The ID of the tag.
+    pub tag_id: u64,
+    /// The name of the tag.
+    pub tag_name: String,
+}
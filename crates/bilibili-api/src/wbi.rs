@@ -0,0 +1,124 @@
+//! WBI signing for BiliBili web APIs.
+//!
+//! See <https://github.com/SocialSisterYi/bilibili-API-collect/blob/master/docs/misc/sign/wbi.md>.
+
+use md5::{Digest, Md5};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+const MIXIN_KEY_ENC_TAB: [usize; 64] = [
+    46, 47, 18, 2, 53, 8, 23, 32, 15, 50, 10, 31, 58, 3, 45, 35, 27, 43, 5, 49, 33, 9, 42, 19, 29,
+    28, 14, 39, 12, 38, 41, 13, 37, 48, 7, 16, 24, 55, 40, 61, 26, 17, 0, 1, 60, 51, 30, 4, 22, 25,
+    54, 21, 56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
+];
+
+/// Characters left as-is by `encodeURIComponent`.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~')
+    .remove(b'!')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// The mixin key used to sign requests, derived from `img_key` and `sub_key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MixinKey(String);
+
+impl MixinKey {
+    pub(crate) fn new(img_key: &str, sub_key: &str) -> Self {
+        let raw: Vec<char> = img_key.chars().chain(sub_key.chars()).collect();
+        let key = MIXIN_KEY_ENC_TAB
+            .iter()
+            .filter_map(|&idx| raw.get(idx))
+            .take(32)
+            .collect();
+
+        Self(key)
+    }
+
+    /// Extracts the key from an URL like `https://i0.hdslb.com/bfs/wbi/{key}.png`.
+    pub(crate) fn key_from_url(url: &str) -> &str {
+        let name = url.rsplit('/').next().unwrap_or(url);
+        name.split('.').next().unwrap_or(name)
+    }
+
+    /// Signs `params` at the given Unix timestamp, returning the signed query string.
+    pub(crate) fn sign(&self, params: &[(&str, String)], wts: u64) -> String {
+        let wts = wts.to_string();
+        let mut params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .chain(std::iter::once(("wts", wts.as_str())))
+            .collect();
+        params.sort_by_key(|(key, _)| *key);
+
+        let query = params
+            .into_iter()
+            .map(|(key, value)| {
+                let value: String = value.chars().filter(|c| !"!'()*".contains(*c)).collect();
+                format!(
+                    "{}={}",
+                    utf8_percent_encode(key, COMPONENT),
+                    utf8_percent_encode(&value, COMPONENT)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let digest = Md5::digest(format!("{query}{key}", key = self.0).as_bytes());
+        let w_rid: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+
+        format!("{query}&w_rid={w_rid}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::MixinKey;
+
+    #[test]
+    fn mixin_key_ok() {
+        let key = MixinKey::new(
+            "7cd084941338484aae1ad9425b84077c",
+            "4932caff0ff746eab6f01bf08b70ac45",
+        );
+
+        assert_eq!(key.0, "ea1db124af3c7062474693fa704f4ff8");
+    }
+
+    #[test]
+    fn key_from_url_ok() {
+        assert_eq!(
+            MixinKey::key_from_url(
+                "https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png"
+            ),
+            "7cd084941338484aae1ad9425b84077c"
+        );
+    }
+
+    #[test]
+    fn sign_ok() {
+        let key = MixinKey::new(
+            "7cd084941338484aae1ad9425b84077c",
+            "4932caff0ff746eab6f01bf08b70ac45",
+        );
+        let query = key.sign(
+            &[
+                ("foo", "114".to_string()),
+                ("bar", "514".to_string()),
+                ("zab", "1919810".to_string()),
+            ],
+            1702204169,
+        );
+
+        assert_eq!(
+            query,
+            "bar=514&foo=114&wts=1702204169&zab=1919810&w_rid=8f6f2b5b3d485fe1886cec6a0be8c5d4"
+        );
+    }
+}
@@ -11,6 +11,7 @@ use std::{
 use url::Url;
 
 use crate::IllustTagsInfoExt;
+use crate::format::locale::Language;
 
 /// The config of Fuuka bot.
 #[derive(Deserialize, Debug, Clone)]
@@ -22,6 +23,9 @@ pub struct Config {
     pub matrix: MatrixConfig,
     /// Admin user ID.
     pub admin_user: Option<OwnedUserId>,
+    /// Default language of the replies.
+    #[serde(default)]
+    pub language: Language,
     /// Pixiv related configs.
     #[serde(default)]
     pub pixiv: PixivConfig,
@@ -67,6 +71,11 @@ pub enum PixivConfig {
         token: SecretString,
         r18: bool,
         tag_triggers: TagTriggers,
+        digests: Vec<RankingDigestConfig>,
+        /// Maximum size of an image to upload, in bytes.
+        max_upload_size: u64,
+        /// How often to check if the session is still logged in.
+        session_check_interval: Duration,
     },
 }
 
@@ -89,31 +98,132 @@ impl<'de> Deserialize<'de> for PixivConfig {
                 r18: bool,
                 #[serde(default)]
                 tag_triggers: TagTriggers,
+                #[serde(default)]
+                digests: Vec<RankingDigestConfig>,
+                #[serde(
+                    rename = "max-upload-size",
+                    default = "pixiv_config_default_max_upload_size"
+                )]
+                max_upload_size: u64,
+                #[serde(
+                    rename = "session-check-interval",
+                    default = "pixiv_config_default_session_check_interval",
+                    deserialize_with = "deserialize_duration_from_seconds"
+                )]
+                session_check_interval: Duration,
             },
         }
-        PixivConfig::deserialize(deserializer).map(|value| match value {
-            PixivConfig::Disabled { enabled: _ } => Self::Disabled,
+        PixivConfig::deserialize(deserializer).and_then(|value| match value {
+            PixivConfig::Disabled { enabled: _ } => Ok(Self::Disabled),
+            PixivConfig::Enabled {
+                session_check_interval,
+                ..
+            } if session_check_interval.is_zero() => Err(serde::de::Error::custom(
+                "session-check-interval must be at least 1 second",
+            )),
             PixivConfig::Enabled {
                 enabled: _,
                 token,
                 r18,
                 tag_triggers,
-            } => Self::Enabled {
+                digests,
+                max_upload_size,
+                session_check_interval,
+            } => Ok(Self::Enabled {
                 token,
                 r18,
                 tag_triggers,
-            },
+                digests,
+                max_upload_size,
+                session_check_interval,
+            }),
         })
     }
 }
 
+/// A Pixiv ranking posted to a room on schedule.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct RankingDigestConfig {
+    /// The room to post to.
+    pub room: OwnedRoomId,
+    /// When to post.
+    pub cron: Box<CronSchedule>,
+    /// The ranking mode.
+    #[serde(default)]
+    pub mode: crate::message::pixiv::RankingMode,
+    /// The type of works in the ranking.
+    #[serde(default)]
+    pub content: crate::message::pixiv::RankingContent,
+    /// How many items to post.
+    #[serde(default = "ranking_digest_config_default_count")]
+    pub count: usize,
+    /// Whether to attach the thumbnails.
+    #[serde(default)]
+    pub thumbnails: bool,
+}
+
+/// A tag trigger rule.
+///
+/// The rule matches when all of the given conditions are met,
+/// a rule without any of `all`, `any`, `patterns` and `authors` never matches.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct TriggerItem {
-    pub required_tags: Vec<String>,
+    /// The illust should have all of these untranslated tags.
+    #[serde(default)]
+    pub all: Vec<String>,
+    /// The illust should have any of these untranslated tags.
+    #[serde(default, alias = "required-tags")]
+    pub any: Vec<String>,
+    /// The illust should have none of these untranslated tags.
+    #[serde(default)]
+    pub none: Vec<String>,
+    /// Any tag of the illust, either untranslated or translated, should match any of these patterns.
+    #[serde(default)]
+    pub patterns: Vec<TagPattern>,
+    /// The illust should be created by any of these users.
+    #[serde(default)]
+    pub authors: Vec<u64>,
     pub target: String,
 }
 
+impl TriggerItem {
+    /// Check if the illust with `tags` matches this rule.
+    pub fn matches(&self, tags: &pixiv_ajax_api::illust::IllustTagsInfo) -> bool {
+        if self.all.is_empty()
+            && self.any.is_empty()
+            && self.patterns.is_empty()
+            && self.authors.is_empty()
+        {
+            return false;
+        }
+
+        self.all.iter().all(|tag| tags.has_tag(tag))
+            && (self.any.is_empty() || self.any.iter().any(|tag| tags.has_tag(tag)))
+            && !self.none.iter().any(|tag| tags.has_tag(tag))
+            && (self.patterns.is_empty()
+                || self
+                    .patterns
+                    .iter()
+                    .any(|pattern| tags.has_tag_matching(&pattern.0)))
+            && (self.authors.is_empty() || self.authors.contains(&tags.author_id))
+    }
+}
+
+/// A regular expression matched against tags.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(try_from = "String")]
+pub struct TagPattern(pub regex::Regex);
+
+impl TryFrom<String> for TagPattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        regex::Regex::new(&value).map(Self)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TagTriggers {
     pub(crate) room_scoped_config: HashMap<OwnedRoomId, Vec<TriggerItem>>,
@@ -209,6 +319,32 @@ impl FeaturesConfig {
             .map(|res| res.pixiv_r18)
             .unwrap_or_default()
     }
+
+    pub fn room_pixiv_r18_preview_enabled(&self, room_id: &RoomId) -> bool {
+        self.0
+            .get(room_id)
+            .map(|res| res.pixiv_r18_preview)
+            .unwrap_or_default()
+    }
+
+    pub fn room_pixiv_ai_filter(&self, room_id: &RoomId) -> PixivAIFilter {
+        self.0
+            .get(room_id)
+            .map(|res| res.pixiv_ai)
+            .unwrap_or_default()
+    }
+
+    pub fn room_pixiv_image_size(&self, room_id: &RoomId) -> PixivImageSize {
+        self.0
+            .get(room_id)
+            .map(|res| res.pixiv_image_size)
+            .unwrap_or_default()
+    }
+
+    /// Returns the language set for the room, if any.
+    pub fn room_language(&self, room_id: &RoomId) -> Option<Language> {
+        self.0.get(room_id).and_then(|res| res.language)
+    }
 }
 
 /// Sticker feature config.
@@ -235,6 +371,56 @@ pub struct RoomFeatures {
     /// Enable pixiv (R18).
     #[serde(default)]
     pub pixiv_r18: bool,
+    /// Send a pixelated preview of pixiv R18 illusts if pixiv (R18) is not enabled.
+    #[serde(default)]
+    pub pixiv_r18_preview: bool,
+    /// How AI-generated Pixiv works are handled.
+    #[serde(default)]
+    pub pixiv_ai: PixivAIFilter,
+    /// The size of Pixiv images to send.
+    #[serde(default)]
+    pub pixiv_image_size: PixivImageSize,
+    /// The language of the replies, overriding the default one.
+    #[serde(default)]
+    pub language: Option<Language>,
+}
+
+/// The size of Pixiv images sent in a room.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum PixivImageSize {
+    /// Up to 540px on the longer side.
+    Small,
+    /// Up to 1200px on the longer side.
+    Regular,
+    /// The original image, falling back to [PixivImageSize::Regular] if it's too large.
+    #[default]
+    Original,
+}
+
+/// How AI-generated Pixiv works are handled in a room.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum PixivAIFilter {
+    /// Send them like other works.
+    #[default]
+    Allow,
+    /// Send them with a label.
+    Label,
+    /// Don't send them at all.
+    Hide,
+}
+
+impl PixivAIFilter {
+    /// Whether a work of `ai_type` should not be sent.
+    pub fn hides(self, ai_type: pixiv_ajax_api::common::AIType) -> bool {
+        self == Self::Hide && ai_type == pixiv_ajax_api::common::AIType::AI
+    }
+
+    /// Whether a work of `ai_type` should be sent with a label.
+    pub fn labels(self, ai_type: pixiv_ajax_api::common::AIType) -> bool {
+        self == Self::Label && ai_type == pixiv_ajax_api::common::AIType::AI
+    }
 }
 
 /// Configure various backend APIs
@@ -327,38 +513,22 @@ impl TryFrom<String> for RepositoryParts {
 }
 
 impl TagTriggers {
+    /// Returns the targets of all matching rules, both global and scoped to `room_id`.
     pub fn check_for_tag_triggers(
         &self,
         tags: &pixiv_ajax_api::illust::IllustTagsInfo,
         room_id: &RoomId,
-    ) -> Option<&str> {
-        if let Some(infos) = self.room_scoped_config.get(room_id) {
-            for item in infos {
-                if tags.has_any_tag(
-                    &item
-                        .required_tags
-                        .iter()
-                        .map(AsRef::as_ref)
-                        .collect::<Vec<&str>>(),
-                ) {
-                    return Some(&item.target);
-                }
-            }
-        } else {
-            for item in &self.global_config {
-                if tags.has_any_tag(
-                    &item
-                        .required_tags
-                        .iter()
-                        .map(AsRef::as_ref)
-                        .collect::<Vec<&str>>(),
-                ) {
-                    return Some(&item.target);
-                }
+    ) -> Vec<&str> {
+        let room_scoped = self.room_scoped_config.get(room_id).into_iter().flatten();
+
+        let mut targets: Vec<&str> = Vec::new();
+        for item in self.global_config.iter().chain(room_scoped) {
+            if item.matches(tags) && !targets.contains(&item.target.as_str()) {
+                targets.push(&item.target);
             }
         }
 
-        None
+        targets
     }
 }
 
@@ -428,6 +598,22 @@ fn github_config_default_base_url() -> Url {
     "https://api.github.com".parse().unwrap()
 }
 
+/// Returns the default count of ranking digest items,
+/// which is 10.
+fn ranking_digest_config_default_count() -> usize {
+    10
+}
+
+/// Returns the default maximum size of an image to upload, 10 MiB.
+fn pixiv_config_default_max_upload_size() -> u64 {
+    10 * 1024 * 1024
+}
+
+/// Returns the default interval of Pixiv session checks, 1 hour.
+fn pixiv_config_default_session_check_interval() -> Duration {
+    Duration::from_secs(60 * 60)
+}
+
 /// Returns the default duration of Matrix connection timeout,
 /// which is 5 minutes.
 fn matrix_config_default_timeout() -> Duration {
@@ -443,3 +629,186 @@ where
     let duration_sec = u64::deserialize(deserializer)?;
     Ok(Duration::from_secs(duration_sec))
 }
+
+#[cfg(test)]
+mod tests {
+    use matrix_sdk::ruma::room_id;
+    use pixiv_ajax_api::illust::IllustTagsInfo;
+    use pretty_assertions::assert_eq;
+
+    use super::{PixivConfig, TagTriggers};
+
+    fn tags() -> IllustTagsInfo {
+        serde_json::from_value(serde_json::json!({
+            "authorId": "78951133",
+            "isLocked": false,
+            "writable": true,
+            "tags": [
+                {
+                    "tag": "ホタル(スターレイル)",
+                    "locked": true,
+                    "deletable": false,
+                    "translation": { "en": "流萤（星穹铁道）" },
+                },
+                {
+                    "tag": "崩壊スターレイル",
+                    "locked": true,
+                    "deletable": false,
+                    "translation": { "en": "崩坏：星穹铁道" },
+                },
+                {
+                    "tag": "女の子",
+                    "locked": true,
+                    "deletable": false,
+                },
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Config {
+        tag_triggers: TagTriggers,
+    }
+
+    fn triggers(config: &str) -> TagTriggers {
+        toml::from_str::<Config>(config).unwrap().tag_triggers
+    }
+
+    #[test]
+    fn tag_triggers_tag_sets() {
+        let triggers = triggers(
+            r#"
+            [[tag-triggers]]
+            all = ["ホタル(スターレイル)", "女の子"]
+            target = "all"
+
+            [[tag-triggers]]
+            all = ["ホタル(スターレイル)", "男の子"]
+            target = "all-missing"
+
+            [[tag-triggers]]
+            any = ["男の子", "女の子"]
+            target = "any"
+
+            [[tag-triggers]]
+            required-tags = ["崩壊スターレイル"]
+            target = "required-tags"
+
+            [[tag-triggers]]
+            any = ["女の子"]
+            none = ["崩壊スターレイル"]
+            target = "none"
+
+            [[tag-triggers]]
+            none = ["男の子"]
+            target = "only-none"
+            "#,
+        );
+
+        assert_eq!(
+            triggers.check_for_tag_triggers(&tags(), room_id!("!room:example.org")),
+            vec!["all", "any", "required-tags"]
+        );
+    }
+
+    #[test]
+    fn tag_triggers_patterns_and_authors() {
+        let triggers = triggers(
+            r#"
+            [[tag-triggers]]
+            patterns = ["^ホタル"]
+            target = "original"
+
+            [[tag-triggers]]
+            patterns = ["^流萤"]
+            target = "translated"
+
+            [[tag-triggers]]
+            patterns = ["^Firefly$"]
+            target = "unmatched"
+
+            [[tag-triggers]]
+            authors = [78951133]
+            target = "author"
+
+            [[tag-triggers]]
+            authors = [1]
+            target = "other-author"
+            "#,
+        );
+
+        assert_eq!(
+            triggers.check_for_tag_triggers(&tags(), room_id!("!room:example.org")),
+            vec!["original", "translated", "author"]
+        );
+    }
+
+    #[test]
+    fn tag_triggers_room_rules_add_to_global_rules() {
+        let triggers = triggers(
+            r#"
+            [[tag-triggers]]
+            any = ["女の子"]
+            target = "global"
+
+            [[tag-triggers]]
+            rooms = ["!room:example.org"]
+            any = ["崩壊スターレイル"]
+            target = "room"
+
+            [[tag-triggers]]
+            rooms = ["!room:example.org"]
+            any = ["女の子"]
+            target = "global"
+            "#,
+        );
+
+        assert_eq!(
+            triggers.check_for_tag_triggers(&tags(), room_id!("!room:example.org")),
+            vec!["global", "room"]
+        );
+        assert_eq!(
+            triggers.check_for_tag_triggers(&tags(), room_id!("!other:example.org")),
+            vec!["global"]
+        );
+    }
+
+    #[test]
+    fn tag_triggers_invalid_pattern() {
+        let result = toml::from_str::<Config>(
+            r#"
+            [[tag-triggers]]
+            patterns = ["("]
+            target = "invalid"
+            "#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn pixiv_session_check_interval() {
+        let config = |interval: u64| {
+            toml::from_str::<PixivConfig>(&format!(
+                r#"
+                enabled = true
+                token = "token"
+                r18 = false
+                session-check-interval = {interval}
+                "#
+            ))
+        };
+
+        let Ok(PixivConfig::Enabled {
+            session_check_interval,
+            ..
+        }) = config(60)
+        else {
+            panic!("Pixiv should be enabled");
+        };
+        assert_eq!(session_check_interval, std::time::Duration::from_secs(60));
+        assert!(config(0).is_err());
+    }
+}
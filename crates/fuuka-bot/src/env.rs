@@ -56,6 +56,7 @@ mod inner {
 static CREDENTIALS_FILE: &str = "credentials.json";
 static CONFIG_FILE: &str = "fuuka-bot.toml";
 static JWK_TOKEN_FILE: &str = "fuuka-bot.jwk.json";
+static TEMPLATES_DIR: &str = "templates";
 
 pub(super) fn config() -> anyhow::Result<crate::Config> {
     let file = self::inner::config(CONFIG_FILE)?;
@@ -65,6 +66,10 @@ pub(super) fn config() -> anyhow::Result<crate::Config> {
     Ok(config)
 }
 
+pub(super) fn templates() -> Result<PathBuf, VarError> {
+    self::inner::config(TEMPLATES_DIR)
+}
+
 pub(super) fn store() -> Result<PathBuf, VarError> {
     self::inner::state("store")
 }
@@ -0,0 +1,124 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub url: &'a str,
+    pub title: &'a str,
+    pub evaluate: Option<&'a str>,
+    pub rating: Option<Rating>,
+    /// Describes the progress of the season, like `已完结, 全12话`.
+    pub progress: Option<&'a str>,
+    /// The linked episode, if any.
+    pub episode: Option<Episode<'a>>,
+}
+
+#[derive(serde::Serialize)]
+pub struct Rating {
+    pub score: f64,
+    pub count: u64,
+}
+
+#[derive(serde::Serialize)]
+pub struct Episode<'a> {
+    pub title: &'a str,
+    pub long_title: Option<&'a str>,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/bangumi.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("bangumi.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/bangumi.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("bangumi.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Episode, Rating};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            url: "https://www.bilibili.com/bangumi/play/ep805450",
+            title: "葬送的芙莉莲",
+            evaluate: Some("打倒魔王的勇者一行人的魔法使芙莉莲。"),
+            rating: Some(Rating {
+                score: 9.8,
+                count: 204871,
+            }),
+            progress: Some("已完结, 全28话"),
+            episode: Some(Episode {
+                title: "1",
+                long_title: Some("冒险的终点"),
+            }),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://www.bilibili.com/bangumi/play/ep805450\">葬送的芙莉莲 · 1 冒险的终点</a>",
+                "</p>\n",
+                "<p>",
+                "⭐ 9.8 (204871) · 已完结, 全28话",
+                "</p>",
+                "<details><summary>Description</summary><blockquote>打倒魔王的勇者一行人的魔法使芙莉莲。</blockquote></details>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            url: "https://www.bilibili.com/bangumi/play/ss45969",
+            title: "葬送的芙莉莲",
+            evaluate: None,
+            rating: None,
+            progress: Some("连载中, 更新至第10话"),
+            episode: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "葬送的芙莉莲 https://www.bilibili.com/bangumi/play/ss45969",
+                "\n",
+                "⭐ - · 连载中, 更新至第10话",
+            )
+        );
+    }
+}
@@ -0,0 +1,116 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub room_id: u64,
+    pub title: &'a str,
+    /// One of `live`, `offline` or `rotating`.
+    pub status: &'a str,
+    pub viewers: u64,
+    pub area: &'a str,
+    pub streamer: Streamer<'a>,
+}
+
+#[derive(serde::Serialize)]
+pub struct Streamer<'a> {
+    pub mid: u64,
+    pub name: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/live.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("live.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/live.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("live.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Streamer};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            room_id: 21452505,
+            title: "【B限】玩游戏",
+            status: "live",
+            viewers: 114514,
+            area: "虚拟主播",
+            streamer: Streamer {
+                mid: 434334701,
+                name: "七海Nana7mi",
+            },
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<b>🔴 Live</b> ",
+                "<a href=\"https://live.bilibili.com/21452505\">【B限】玩游戏</a>",
+                " | ",
+                "<a href=\"https://space.bilibili.com/434334701\">@七海Nana7mi</a>",
+                "</p>\n",
+                "<p>",
+                "👀 114514 · 虚拟主播",
+                "</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            room_id: 21452505,
+            title: "【B限】玩游戏",
+            status: "offline",
+            viewers: 0,
+            area: "虚拟主播",
+            streamer: Streamer {
+                mid: 434334701,
+                name: "七海Nana7mi",
+            },
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "⚫ Offline ",
+                "【B限】玩游戏 https://live.bilibili.com/21452505",
+                " | ",
+                "@七海Nana7mi https://space.bilibili.com/434334701",
+                "\n",
+                "👀 0 · 虚拟主播",
+            )
+        );
+    }
+}
@@ -1 +1,4 @@
+pub mod bangumi;
+pub mod live;
+pub mod user;
 pub mod video;
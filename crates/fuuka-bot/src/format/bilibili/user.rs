@@ -0,0 +1,142 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub mid: u64,
+    pub name: &'a str,
+    pub sign: Option<&'a str>,
+    pub followers: u64,
+    pub videos_count: u64,
+    /// The latest videos.
+    pub videos: &'a [Video<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Video<'a> {
+    pub bvid: &'a str,
+    pub title: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/user.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("user.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/bilibili/user.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("user.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Video};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            mid: 486906719,
+            name: "索尼音乐中国",
+            sign: Some("索尼音乐中国官方账号"),
+            followers: 1453729,
+            videos_count: 3306,
+            videos: &[
+                Video {
+                    bvid: "BV1GJ411x7h7",
+                    title: "【官方 MV】Never Gonna Give You Up - Rick Astley",
+                },
+                Video {
+                    bvid: "BV1xx411c7mD",
+                    title: "字幕君交流场所",
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://space.bilibili.com/486906719\">@索尼音乐中国</a>",
+                "</p>\n",
+                "<p>",
+                "👥 1453729 · 🎞️ 3306",
+                "</p>",
+                "<blockquote>索尼音乐中国官方账号</blockquote>",
+                "<p>Latest videos:</p>",
+                "<ul>",
+                "<li><a href=\"https://www.bilibili.com/video/BV1GJ411x7h7\">【官方 MV】Never Gonna Give You Up - Rick Astley</a></li>",
+                "<li><a href=\"https://www.bilibili.com/video/BV1xx411c7mD\">字幕君交流场所</a></li>",
+                "</ul>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            mid: 486906719,
+            name: "索尼音乐中国",
+            sign: Some("索尼音乐中国官方账号"),
+            followers: 1453729,
+            videos_count: 3306,
+            videos: &[
+                Video {
+                    bvid: "BV1GJ411x7h7",
+                    title: "【官方 MV】Never Gonna Give You Up - Rick Astley",
+                },
+                Video {
+                    bvid: "BV1xx411c7mD",
+                    title: "字幕君交流场所",
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "@索尼音乐中国 https://space.bilibili.com/486906719",
+                "\n",
+                "👥 1453729 · 🎞️ 3306",
+                "\n",
+                "> 索尼音乐中国官方账号",
+                "\n",
+                "Latest videos:",
+                "\n",
+                "• 【官方 MV】Never Gonna Give You Up - Rick Astley https://www.bilibili.com/video/BV1GJ411x7h7",
+                "\n",
+                "• 字幕君交流场所 https://www.bilibili.com/video/BV1xx411c7mD",
+            )
+        );
+    }
+}
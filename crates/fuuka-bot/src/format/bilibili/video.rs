@@ -1,6 +1,12 @@
+use crate::format::locale::Language;
+
 #[derive(serde::Serialize)]
 pub struct Context<'a> {
-    pub id: &'a str,
+    pub language: Language,
+    /// The BV ID, like `BV1GJ411x7h7`.
+    pub bvid: &'a str,
+    /// The AV ID, without the `av` prefix.
+    pub aid: u64,
     pub title: &'a str,
     pub description: Option<&'a str>,
     pub tags: &'a [&'a str],
@@ -57,13 +63,16 @@ mod tests {
     use super::{Author, Context, Counts};
 
     use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
 
     #[test]
     fn format_html_test() {
         use html_compare_rs::assert_html_eq;
 
         let context: Context<'static> = Context {
-            id: "BV1GJ411x7h7",
+            language: Language::En,
+            bvid: "BV1GJ411x7h7",
+            aid: 80433022,
             title: "【官方 MV】Never Gonna Give You Up - Rick Astley",
             description: None,
             tags: &[
@@ -96,7 +105,7 @@ mod tests {
             concat!(
                 "<p>",
                 "<a href=\"https://www.bilibili.com/video/BV1GJ411x7h7\">【官方 MV】Never Gonna Give You Up - Rick Astley</a>",
-                " | ",
+                " | av80433022 | ",
                 "<a href=\"https://space.bilibili.com/486906719\">@索尼音乐中国</a>",
                 "</p>\n",
                 "<p>",
@@ -119,7 +128,9 @@ mod tests {
         use pretty_assertions::assert_str_eq;
 
         let context: Context<'static> = Context {
-            id: "BV1GJ411x7h7",
+            language: Language::En,
+            bvid: "BV1GJ411x7h7",
+            aid: 80433022,
             title: "【官方 MV】Never Gonna Give You Up - Rick Astley",
             description: None,
             tags: &[
@@ -151,7 +162,7 @@ mod tests {
             result,
             concat!(
                 "【官方 MV】Never Gonna Give You Up - Rick Astley https://www.bilibili.com/video/BV1GJ411x7h7",
-                " | ",
+                " | av80433022 | ",
                 "@索尼音乐中国 https://space.bilibili.com/486906719",
                 "\n",
                 "▶️ 94702144 · 👍 2573660 · 🪙 1114961 · 🌟 1346712 · 🪧 132903 · 💬 176755 · ↗️ 420758",
@@ -171,7 +182,9 @@ mod tests {
         use html_compare_rs::assert_html_eq;
 
         let context: Context<'static> = Context {
-            id: "BV1o44y1v7Bx",
+            language: Language::En,
+            bvid: "BV1o44y1v7Bx",
+            aid: 976340828,
             title: "厨 房 好 搭 档",
             description: Some(
                 "第一次做这种台词比较多的鬼畜，做的不是很好，希望喜欢这个视频的小伙伴可以给个三连支持一下！！！",
@@ -210,7 +223,7 @@ mod tests {
             concat!(
                 "<p>",
                 "<a href=\"https://www.bilibili.com/video/BV1o44y1v7Bx\">厨 房 好 搭 档</a>",
-                " | ",
+                " | av976340828 | ",
                 "<a href=\"https://space.bilibili.com/341243751\">@To-Go玩家阳</a>",
                 "</p>\n",
                 "<p>",
@@ -238,7 +251,9 @@ mod tests {
         use pretty_assertions::assert_str_eq;
 
         let context: Context<'static> = Context {
-            id: "BV1o44y1v7Bx",
+            language: Language::En,
+            bvid: "BV1o44y1v7Bx",
+            aid: 976340828,
             title: "厨 房 好 搭 档",
             description: Some(
                 "第一次做这种台词比较多的鬼畜，做的不是很好，希望喜欢这个视频的小伙伴可以给个三连支持一下！！！",
@@ -276,7 +291,7 @@ mod tests {
             result,
             concat!(
                 "厨 房 好 搭 档 https://www.bilibili.com/video/BV1o44y1v7Bx",
-                " | ",
+                " | av976340828 | ",
                 "@To-Go玩家阳 https://space.bilibili.com/341243751",
                 "\n",
                 "▶️ 1518364 · 👍 75281 · 🪙 12573 · 🌟 29925 · 🪧 1416 · 💬 659 · ↗️ 7444",
@@ -302,7 +317,9 @@ mod tests {
         use html_compare_rs::assert_html_eq;
 
         let context: Context<'static> = Context {
-            id: "BV13yJ1zUEmH",
+            language: Language::En,
+            bvid: "BV13yJ1zUEmH",
+            aid: 115253316683150,
             title: "魔女审判混进了奇怪的人",
             description: Some(
                 "粉色小奶狗是对的！！！\n太可爱了艾呀玛...\n\n\n咱上大学了，要苦逼上早晚八\n所以之后更新就随缘喽\n（不过本来好像就是随缘）",
@@ -341,7 +358,7 @@ mod tests {
             concat!(
                 "<p>",
                 "<a href=\"https://www.bilibili.com/video/BV13yJ1zUEmH\">魔女审判混进了奇怪的人</a>",
-                " | ",
+                " | av115253316683150 | ",
                 "<a href=\"https://space.bilibili.com/29484733\">@Chaos-GofG</a>",
                 "</p>\n",
                 "<p>",
@@ -369,7 +386,9 @@ mod tests {
         use pretty_assertions::assert_str_eq;
 
         let context: Context<'static> = Context {
-            id: "BV13yJ1zUEmH",
+            language: Language::En,
+            bvid: "BV13yJ1zUEmH",
+            aid: 115253316683150,
             title: "魔女审判混进了奇怪的人",
             description: Some(
                 "粉色小奶狗是对的！！！\n太可爱了艾呀玛...\n\n\n咱上大学了，要苦逼上早晚八\n所以之后更新就随缘喽\n（不过本来好像就是随缘）",
@@ -407,7 +426,7 @@ mod tests {
             result,
             concat!(
                 "魔女审判混进了奇怪的人 https://www.bilibili.com/video/BV13yJ1zUEmH",
-                " | ",
+                " | av115253316683150 | ",
                 "@Chaos-GofG https://space.bilibili.com/29484733",
                 "\n",
                 "▶️ 221938 · 👍 11831 · 🪙 2320 · 🌟 6169 · 🪧 1196 · 💬 690 · ↗️ 5748",
@@ -427,4 +446,56 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn format_html_test_escapes_untrusted_text() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            bvid: "BV13yJ1zUEmH",
+            aid: 115253316683150,
+            title: "<b>Title</b>",
+            description: Some(
+                "<a href=\"https://matrix.to/#/@admin:example.org\">@admin</a>\n& more",
+            ),
+            tags: &["<tag>"],
+            author: Author {
+                id: 29484733,
+                name: "Chaos-GofG",
+            },
+            counts: Counts {
+                view: 0,
+                danmaku: 0,
+                reply: 0,
+                favorite: 0,
+                coin: 0,
+                share: 0,
+                like: 0,
+            },
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://www.bilibili.com/video/BV13yJ1zUEmH\">&lt;b&gt;Title&lt;&#x2f;b&gt;</a>",
+                " | av115253316683150 | ",
+                "<a href=\"https://space.bilibili.com/29484733\">@Chaos-GofG</a>",
+                "</p>\n",
+                "<p>",
+                "▶️ 0 · 👍 0 · 🪙 0 · 🌟 0 · 🪧 0 · 💬 0 · ↗️ 0",
+                "</p>\n",
+                "<details><summary>Description</summary><blockquote>",
+                "&lt;a href=&quot;https:&#x2f;&#x2f;matrix.to&#x2f;#&#x2f;@admin:example.org&quot;&gt;@admin&lt;&#x2f;a&gt;",
+                "<br/>&amp; more",
+                "</blockquote></details>",
+                "<p>",
+                "<font color=\"#3771bb\">#&lt;tag&gt;#</font>",
+                "</p>",
+            )
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub name: &'a str,
+    pub version: &'a str,
+    pub description: Option<&'a str>,
+    /// The minimum supported Rust version.
+    pub msrv: Option<&'a str>,
+    pub docs: &'a str,
+    pub repository: Option<&'a str>,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/crates/info.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("info.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/crates/info.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("info.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            name: "syn",
+            version: "2.0.106",
+            description: Some("Parser for Rust source code"),
+            msrv: Some("1.61"),
+            docs: "https://docs.rs/syn",
+            repository: Some("https://github.com/dtolnay/syn"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p><b>[Rust/Crate]</b> syn v2.0.106: Parser for Rust source code</p>\n",
+                "<p>",
+                "MSRV: 1.61<br/>",
+                "Docs: <a href=\"https://docs.rs/syn\">https://docs.rs/syn</a><br/>",
+                "Repository: <a href=\"https://github.com/dtolnay/syn\">https://github.com/dtolnay/syn</a>",
+                "</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            name: "syn",
+            version: "2.0.106",
+            description: Some("Parser for Rust source code"),
+            msrv: Some("1.61"),
+            docs: "https://docs.rs/syn",
+            repository: Some("https://github.com/dtolnay/syn"),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[Rust/Crate] syn v2.0.106: Parser for Rust source code",
+                "\n",
+                "MSRV: 1.61",
+                "\n",
+                "Docs: https://docs.rs/syn",
+                "\n",
+                "Repository: https://github.com/dtolnay/syn",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_without_optional_fields() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            name: "fuuka-bot",
+            version: "0.1.0",
+            description: None,
+            msrv: None,
+            docs: "https://docs.rs/fuuka-bot/0.1.0",
+            repository: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[Rust/Crate] fuuka-bot v0.1.0: (No Description)",
+                "\n",
+                "Docs: https://docs.rs/fuuka-bot/0.1.0",
+            )
+        );
+    }
+}
@@ -0,0 +1,132 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub url: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub path: &'a str,
+    pub lines: Option<Lines>,
+    /// Language hint of the code block, usually the file extension.
+    pub language: Option<&'a str>,
+    /// The referenced lines.
+    pub code: Option<&'a str>,
+}
+
+#[derive(serde::Serialize)]
+pub struct Lines {
+    pub start: usize,
+    pub end: usize,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/blob.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("blob.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/blob.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("blob.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Lines};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/blob/master/src/main.rs#L1-L2",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            path: "src/main.rs",
+            lines: Some(Lines { start: 1, end: 2 }),
+            language: Some("rs"),
+            code: Some("if a < b && c > d {\n}"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://github.com/ShadowRZ/fuuka-bot/blob/master/src/main.rs#L1-L2\">ShadowRZ/fuuka-bot:src/main.rs</a>",
+                " (L1-L2)",
+                "</p>\n",
+                "<pre><code class=\"language-rs\">if a &lt; b &amp;&amp; c &gt; d {\n}</code></pre>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/blob/master/src/main.rs#L1-L2",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            path: "src/main.rs",
+            lines: Some(Lines { start: 1, end: 2 }),
+            language: Some("rs"),
+            code: Some("if a < b && c > d {\n}"),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "ShadowRZ/fuuka-bot:src/main.rs (L1-L2)",
+                " https://github.com/ShadowRZ/fuuka-bot/blob/master/src/main.rs#L1-L2",
+                "\n",
+                "```rs\n",
+                "if a < b && c > d {\n}\n",
+                "```",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_without_lines() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/blob/master/Cargo.toml",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            path: "Cargo.toml",
+            lines: None,
+            language: Some("toml"),
+            code: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            "ShadowRZ/fuuka-bot:Cargo.toml https://github.com/ShadowRZ/fuuka-bot/blob/master/Cargo.toml",
+        );
+    }
+}
@@ -0,0 +1,116 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub url: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub sha: &'a str,
+    /// The first line of the commit message.
+    pub summary: &'a str,
+    pub author: Option<&'a str>,
+    pub stats: Option<Stats>,
+}
+
+#[derive(serde::Serialize)]
+pub struct Stats {
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/commit.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("commit.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/commit.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("commit.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Stats};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/commit/0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            sha: "0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9",
+            summary: "Migrate BiliBili video formatting to minijinja",
+            author: Some("ShadowRZ"),
+            stats: Some(Stats {
+                additions: 120,
+                deletions: 45,
+            }),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://github.com/ShadowRZ/fuuka-bot/commit/0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9\">ShadowRZ/fuuka-bot@<code>0bc93bb</code></a>",
+                " | @ShadowRZ",
+                "</p>\n",
+                "<p>Migrate BiliBili video formatting to minijinja</p>\n",
+                "<p><font color=\"#1a7f37\">+120</font> <font color=\"#d1242f\">-45</font></p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/commit/0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            sha: "0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9",
+            summary: "Migrate BiliBili video formatting to minijinja",
+            author: Some("ShadowRZ"),
+            stats: Some(Stats {
+                additions: 120,
+                deletions: 45,
+            }),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "ShadowRZ/fuuka-bot@0bc93bb https://github.com/ShadowRZ/fuuka-bot/commit/0bc93bb2f4a1d1f0e6e1f0c1b2a3d4e5f6a7b8c9",
+                " | @ShadowRZ",
+                "\n",
+                "Migrate BiliBili video formatting to minijinja",
+                "\n",
+                "+120 -45",
+            )
+        );
+    }
+}
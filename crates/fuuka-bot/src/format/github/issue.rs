@@ -0,0 +1,111 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub url: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub number: u64,
+    pub title: &'a str,
+    pub author: &'a str,
+    /// One of `open`, `closed` or `merged`.
+    pub state: &'a str,
+    /// Whether this is a pull request instead of an issue.
+    pub pull_request: bool,
+    pub labels: &'a [&'a str],
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/issue.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("issue.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/issue.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("issue.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/NixOS/nixpkgs/issues/97479",
+            owner: "NixOS",
+            repo: "nixpkgs",
+            number: 97479,
+            title: "Tracking issue for Rust packaging",
+            author: "jonringer",
+            state: "open",
+            pull_request: false,
+            labels: &["6.topic: rust", "9.needs: reporter feedback"],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<b>[OPEN]</b> ",
+                "<a href=\"https://github.com/NixOS/nixpkgs/issues/97479\">Issue NixOS/nixpkgs#97479: Tracking issue for Rust packaging</a>",
+                " | @jonringer",
+                "</p>\n",
+                "<p>",
+                "<font color=\"#3771bb\">#6.topic: rust</font> ",
+                "<font color=\"#3771bb\">#9.needs: reporter feedback</font>",
+                "</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/NixOS/nixpkgs/pull/300000",
+            owner: "NixOS",
+            repo: "nixpkgs",
+            number: 300000,
+            title: "hello: 2.12.1 -> 2.12.2",
+            author: "r-ryantm",
+            state: "merged",
+            pull_request: true,
+            labels: &[],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[MERGED] PR NixOS/nixpkgs#300000: hello: 2.12.1 -> 2.12.2",
+                " https://github.com/NixOS/nixpkgs/pull/300000",
+                " | @r-ryantm",
+            )
+        );
+    }
+}
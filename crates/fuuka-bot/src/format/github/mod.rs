@@ -0,0 +1,5 @@
+pub mod blob;
+pub mod commit;
+pub mod issue;
+pub mod release;
+pub mod repository;
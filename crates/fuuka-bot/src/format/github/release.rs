@@ -0,0 +1,97 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub url: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub tag: &'a str,
+    pub name: Option<&'a str>,
+    pub author: Option<&'a str>,
+    pub prerelease: bool,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/release.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("release.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/release.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("release.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/releases/tag/v0.4.7",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            tag: "v0.4.7",
+            name: None,
+            author: Some("ShadowRZ"),
+            prerelease: false,
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://github.com/ShadowRZ/fuuka-bot/releases/tag/v0.4.7\">ShadowRZ/fuuka-bot v0.4.7</a>",
+                " | @ShadowRZ",
+                "</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot/releases/tag/v0.5.0-rc.1",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            tag: "v0.5.0-rc.1",
+            name: Some("Fuuka Bot 0.5.0 RC 1"),
+            author: None,
+            prerelease: true,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "ShadowRZ/fuuka-bot Fuuka Bot 0.5.0 RC 1 (Pre-release)",
+                " https://github.com/ShadowRZ/fuuka-bot/releases/tag/v0.5.0-rc.1",
+            )
+        );
+    }
+}
@@ -0,0 +1,127 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub url: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub description: Option<&'a str>,
+    pub language: Option<&'a str>,
+    pub stars: u32,
+    pub forks: u32,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/repository.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("repository.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/github/repository.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("repository.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            description: Some("A Rust Matrix bot."),
+            language: Some("Rust"),
+            stars: 12,
+            forks: 3,
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://github.com/ShadowRZ/fuuka-bot\">ShadowRZ/fuuka-bot</a>",
+                "</p>\n",
+                "<p>⭐ 12 · 🍴 3 · Rust</p>\n",
+                "<blockquote>A Rust Matrix bot.</blockquote>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            description: Some("A Rust Matrix bot."),
+            language: Some("Rust"),
+            stars: 12,
+            forks: 3,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "ShadowRZ/fuuka-bot https://github.com/ShadowRZ/fuuka-bot",
+                "\n",
+                "⭐ 12 · 🍴 3 · Rust",
+                "\n",
+                "> A Rust Matrix bot.",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_without_description() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            url: "https://github.com/ShadowRZ/fuuka-bot",
+            owner: "ShadowRZ",
+            repo: "fuuka-bot",
+            description: None,
+            language: None,
+            stars: 12,
+            forks: 3,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "ShadowRZ/fuuka-bot https://github.com/ShadowRZ/fuuka-bot",
+                "\n",
+                "⭐ 12 · 🍴 3",
+            )
+        );
+    }
+}
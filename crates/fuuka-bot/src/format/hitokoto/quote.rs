@@ -0,0 +1,90 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub uuid: &'a str,
+    pub hitokoto: &'a str,
+    /// Where the sentence is from.
+    pub from: &'a str,
+    /// Who said the sentence.
+    pub from_who: Option<&'a str>,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/hitokoto/quote.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("quote.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/hitokoto/quote.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("quote.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            uuid: "9818ecda-9cbf-4f2a-9af8-8136ef39cfcd",
+            hitokoto: "与众不同的生活方式很累人呢，因为找不到借口。",
+            from: "幸运星",
+            from_who: Some("泉此方"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p><b>『与众不同的生活方式很累人呢，因为找不到借口。』</b><br/>——泉此方「幸运星」</p>\n",
+                "<p>From <a href=\"https://hitokoto.cn/?uuid=9818ecda-9cbf-4f2a-9af8-8136ef39cfcd\">",
+                "https://hitokoto.cn/?uuid=9818ecda-9cbf-4f2a-9af8-8136ef39cfcd</a></p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            uuid: "9818ecda-9cbf-4f2a-9af8-8136ef39cfcd",
+            hitokoto: "与众不同的生活方式很累人呢，因为找不到借口。",
+            from: "幸运星",
+            from_who: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "『与众不同的生活方式很累人呢，因为找不到借口。』——「幸运星」",
+                "\n",
+                "From https://hitokoto.cn/?uuid=9818ecda-9cbf-4f2a-9af8-8136ef39cfcd",
+            )
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::Member;
+
+/// Context of `@from action @to` with an optional suffix.
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub from: Member<'a>,
+    pub to: Member<'a>,
+    pub action: &'a str,
+    /// Text after the target, including the leading space if there should be one.
+    pub suffix: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/action.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("action.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/action.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("action.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Member};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            from: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            to: Member {
+                name: "Bob",
+                uri: "https://matrix.to/#/@bob:example.org",
+            },
+            action: "摸摸了",
+            suffix: " 的<头>",
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<a href=\"https://matrix.to/#/@alice:example.org\">@Alice</a>",
+                " 摸摸了 ",
+                "<a href=\"https://matrix.to/#/@bob:example.org\">@Bob</a>",
+                " 的&lt;头&gt;",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            from: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            to: Member {
+                name: "Bob",
+                uri: "https://matrix.to/#/@bob:example.org",
+            },
+            action: "摸摸了",
+            suffix: " 的<头>",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, "@Alice 摸摸了 @Bob 的<头>");
+    }
+}
@@ -0,0 +1,118 @@
+use super::Member;
+
+/// Context of a user supplied text with `{from}` and `{to}` slots.
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub from: Member<'a>,
+    pub to: Member<'a>,
+    pub segments: &'a [Segment<'a>],
+}
+
+/// A segment of the user supplied text.
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Segment<'a> {
+    Text {
+        text: &'a str,
+    },
+    /// The `{from}` slot.
+    From,
+    /// The `{to}` slot.
+    To,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/formatted.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("formatted.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/formatted.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("formatted.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Member, Segment};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            from: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            to: Member {
+                name: "Bob",
+                uri: "https://matrix.to/#/@bob:example.org",
+            },
+            segments: &[
+                Segment::From,
+                Segment::Text {
+                    text: " <b>hugs</b> ",
+                },
+                Segment::To,
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<a href=\"https://matrix.to/#/@alice:example.org\">@Alice</a>",
+                " &lt;b&gt;hugs&lt;&#x2f;b&gt; ",
+                "<a href=\"https://matrix.to/#/@bob:example.org\">@Bob</a>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            from: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            to: Member {
+                name: "Bob",
+                uri: "https://matrix.to/#/@bob:example.org",
+            },
+            segments: &[
+                Segment::From,
+                Segment::Text {
+                    text: " <b>hugs</b> ",
+                },
+                Segment::To,
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, "@Alice <b>hugs</b> @Bob");
+    }
+}
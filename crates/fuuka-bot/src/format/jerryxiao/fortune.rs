@@ -0,0 +1,226 @@
+use super::Member;
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub member: Member<'a>,
+    /// The matter asked for, or [None] for the fortune of today.
+    pub query: Option<&'a str>,
+    /// Whether the result is a probability.
+    pub prob: bool,
+    /// Whether the probability is of good luck, or of the matter happening.
+    pub lucky: bool,
+    pub result: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/fortune.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("fortune.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/jerryxiao/fortune.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("fortune.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Member};
+    use crate::format::locale::Language;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: None,
+            prob: false,
+            lucky: true,
+            result: "大吉",
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "你好, <a href=\"https://matrix.to/#/@alice:example.org\">@Alice</a><br/>",
+                "汝的今日运势: 大吉",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: None,
+            prob: false,
+            lucky: true,
+            result: "大吉",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, concat!("你好, @Alice", "\n", "汝的今日运势: 大吉"));
+    }
+
+    #[test]
+    fn format_text_test_with_prob() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: None,
+            prob: true,
+            lucky: false,
+            result: "12.34%",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!("你好, @Alice", "\n", "汝今天倒大霉概率是 12.34%")
+        );
+    }
+
+    #[test]
+    fn format_html_test_with_query() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: Some("出门"),
+            prob: true,
+            lucky: true,
+            result: "56.78%",
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "你好, <a href=\"https://matrix.to/#/@alice:example.org\">@Alice</a><br/>",
+                "所求事项: 出门<br/>结果: 此事有 56.78% 的概率发生",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_with_query() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: Some("出门"),
+            prob: false,
+            lucky: false,
+            result: "小凶",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!("你好, @Alice", "\n", "所求事项: 出门\n结果: 小凶")
+        );
+    }
+
+    #[test]
+    fn format_html_test_in_english() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            member: Member {
+                name: "<Alice>",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: Some("<b>Going out</b>"),
+            prob: true,
+            lucky: false,
+            result: "56.78%",
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "Hello, <a href=\"https://matrix.to/#/@alice:example.org\">@&lt;Alice&gt;</a><br/>",
+                "You asked for: &lt;b&gt;Going out&lt;/b&gt;<br/>",
+                "Result: It has a 56.78% chance not to happen",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_in_japanese() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::Ja,
+            member: Member {
+                name: "Alice",
+                uri: "https://matrix.to/#/@alice:example.org",
+            },
+            query: None,
+            prob: true,
+            lucky: true,
+            result: "12.34%",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!("こんにちは、@Alice", "\n", "今日ツイてる確率は 12.34%")
+        );
+    }
+}
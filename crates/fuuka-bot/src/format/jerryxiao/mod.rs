@@ -0,0 +1,11 @@
+pub mod action;
+pub mod formatted;
+pub mod fortune;
+
+/// A room member, shown as a mention pill in HTML.
+#[derive(serde::Serialize)]
+pub struct Member<'a> {
+    pub name: &'a str,
+    /// The `matrix.to` URI of the user.
+    pub uri: &'a str,
+}
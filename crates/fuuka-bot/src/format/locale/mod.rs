@@ -0,0 +1,155 @@
+//! Localized messages.
+//!
+//! Messages of each [Language] are macros in its catalog, a template named
+//! `[fuuka-bot]/templates/locale/<tag>.jinja`, which can be overridden like other templates.
+//!
+//! Templates can import the catalog to localize their contents:
+//!
+//! ```jinja
+//! {% import "[fuuka-bot]/templates/locale/" ~ language ~ ".jinja" as messages %}
+//! {{ messages.done() }}
+//! ```
+use minijinja::{Environment, Value};
+use serde::{Deserialize, Serialize};
+
+/// The language of the replies.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// Simplified Chinese.
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    /// English.
+    #[default]
+    #[serde(rename = "en")]
+    En,
+    /// Japanese.
+    #[serde(rename = "ja")]
+    Ja,
+}
+
+impl Language {
+    /// The language tag, which is also the name of its catalog.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::ZhCn => "zh-CN",
+            Self::En => "en",
+            Self::Ja => "ja",
+        }
+    }
+
+    /// The language requested from Pixiv, which decides the language of tag translations.
+    pub fn pixiv(self) -> &'static str {
+        match self {
+            Self::ZhCn => "zh",
+            Self::En => "en",
+            Self::Ja => "ja",
+        }
+    }
+}
+
+/// The messages of a [Language], with its catalog rendered once to render many messages.
+pub struct Messages<'env> {
+    catalog: minijinja::Captured<'env>,
+}
+
+impl<'env> Messages<'env> {
+    /// Renders the catalog of `language`.
+    pub fn new(env: &'env Environment<'env>, language: Language) -> anyhow::Result<Self> {
+        let catalog = env.get_template(&format!(
+            "[fuuka-bot]/templates/locale/{}.jinja",
+            language.tag()
+        ))?;
+
+        Ok(Self {
+            catalog: catalog.render_captured(())?,
+        })
+    }
+
+    /// Renders the message `name` with `args`.
+    pub fn message(&self, name: &str, args: &[Value]) -> anyhow::Result<String> {
+        Ok(self.catalog.state().call_macro(name, args)?)
+    }
+}
+
+/// Renders the message `name` in `language` with `args`.
+///
+/// Use [Messages] instead to render many messages.
+pub fn message(
+    env: &Environment,
+    language: Language,
+    name: &str,
+    args: &[Value],
+) -> anyhow::Result<String> {
+    Messages::new(env, language)?.message(name, args)
+}
+
+pub fn zh_cn() -> &'static str {
+    include_str!("zh-CN.jinja")
+}
+
+pub fn en() -> &'static str {
+    include_str!("en.jinja")
+}
+
+pub fn ja() -> &'static str {
+    include_str!("ja.jinja")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+    use crate::format::ENVIRONMENT;
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    #[test]
+    fn message_in_each_language() {
+        let render = |language| super::message(&ENVIRONMENT, language, "ping", &["42ms".into()]);
+
+        assert_str_eq!(render(Language::ZhCn).unwrap(), "Pong！用时 42ms");
+        assert_str_eq!(render(Language::En).unwrap(), "Pong after 42ms");
+        assert_str_eq!(render(Language::Ja).unwrap(), "Pong！42ms かかりました");
+    }
+
+    #[test]
+    fn message_with_optional_args() {
+        let render = |link: Option<&str>| {
+            super::message(
+                &ENVIRONMENT,
+                Language::En,
+                "avatar_joined",
+                &[(-1).into(), link.into()],
+            )
+            .unwrap()
+        };
+
+        assert_str_eq!(
+            render(Some("https://example.com/avatar.png")),
+            "-1: Joined with avatar https://example.com/avatar.png"
+        );
+        assert_str_eq!(render(None), "-1: Joined with avatar (No avatar)");
+    }
+
+    #[test]
+    fn catalogs_define_the_same_messages() {
+        let exports = |language: Language| {
+            let catalog = ENVIRONMENT
+                .get_template(&format!(
+                    "[fuuka-bot]/templates/locale/{}.jinja",
+                    language.tag()
+                ))
+                .unwrap();
+            let captured = catalog.render_captured(()).unwrap();
+            let mut exports: Vec<String> = captured
+                .state()
+                .exports()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            exports.sort();
+            exports
+        };
+
+        assert_eq!(exports(Language::En), exports(Language::ZhCn));
+        assert_eq!(exports(Language::Ja), exports(Language::ZhCn));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod rooms;
+pub mod server;
@@ -0,0 +1,137 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub rooms: &'a [Room<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Room<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    /// Whether the room is a DM.
+    pub direct: bool,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/matrix/rooms.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("rooms.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/matrix/rooms.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("rooms.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Room};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            rooms: &[
+                Room {
+                    id: "!aaa:example.org",
+                    name: "<Lobby>",
+                    direct: false,
+                },
+                Room {
+                    id: "!bbb:example.org",
+                    name: "Admin",
+                    direct: true,
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>Joined rooms:</p>\n",
+                "<ul>",
+                "<li>&lt;Lobby&gt; (<code>!aaa:example.org</code>)</li>",
+                "<li>Admin (<code>!bbb:example.org</code>) (DM)</li>",
+                "</ul>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            rooms: &[
+                Room {
+                    id: "!aaa:example.org",
+                    name: "<Lobby>",
+                    direct: false,
+                },
+                Room {
+                    id: "!bbb:example.org",
+                    name: "Admin",
+                    direct: true,
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "Joined rooms:",
+                "\n",
+                "- <Lobby> (!aaa:example.org)",
+                "\n",
+                "- Admin (!bbb:example.org) (DM)",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_in_japanese() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::Ja,
+            rooms: &[Room {
+                id: "!aaa:example.org",
+                name: "Lobby",
+                direct: false,
+            }],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, "参加中のルーム:\n- Lobby (!aaa:example.org)");
+    }
+}
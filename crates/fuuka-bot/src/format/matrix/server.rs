@@ -0,0 +1,87 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub server_name: &'a str,
+    /// The name of the server implementation.
+    pub name: Option<&'a str>,
+    pub version: Option<&'a str>,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/matrix/server.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("server.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/matrix/server.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("server.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            server_name: "example.org",
+            name: Some("Synapse"),
+            version: Some("1.140.0"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(result, "<p><b>example.org</b>: Synapse 1.140.0</p>");
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            server_name: "example.org",
+            name: Some("Synapse"),
+            version: Some("1.140.0"),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, "example.org: Synapse 1.140.0");
+    }
+
+    #[test]
+    fn format_text_test_unknown() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            server_name: "example.org",
+            name: None,
+            version: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(result, "example.org: (Unknown) (Unknown)");
+    }
+}
@@ -11,46 +11,276 @@
 //!
 //! Additional filters are avaliable in [filter],
 //! and are shared between HTML template and text template.
+//!
+//! The environment is created by [environment] at startup,
+//! allowing the built-in templates to be overridden without a rebuild.
+//!
+//! Messages shown in the language of the room are in [locale].
+use std::path::PathBuf;
+#[cfg(test)]
 use std::sync::LazyLock;
 
+use anyhow::Context as _;
 use minijinja::Environment;
 
 pub mod bilibili;
+pub mod crates;
+pub mod github;
+pub mod hitokoto;
+pub mod jerryxiao;
+pub mod locale;
+pub mod matrix;
+pub mod nixpkgs;
 pub mod pixiv;
+pub mod sanitize;
 
-// TODO: Use a bot created environment
-pub static ENVIRONMENT: LazyLock<Environment> = LazyLock::new(|| {
-    let mut env = Environment::new();
-    env.add_template(
+/// Prefix of the names of all built-in templates.
+static PREFIX: &str = "[fuuka-bot]/templates/";
+
+/// A built-in template, with its name and a function returning its contents.
+type Builtin = (&'static str, fn() -> &'static str);
+
+/// All built-in templates.
+static TEMPLATES: &[Builtin] = &[
+    (
         "[fuuka-bot]/templates/pixiv/illust.txt",
-        self::pixiv::illust::text::default(),
-    )
-    .unwrap();
-    env.add_template(
+        self::pixiv::illust::text::default,
+    ),
+    (
         "[fuuka-bot]/templates/pixiv/illust.html",
-        self::pixiv::illust::html::default(),
-    )
-    .unwrap();
-    env.add_template(
+        self::pixiv::illust::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/user.txt",
+        self::pixiv::user::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/user.html",
+        self::pixiv::user::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/search.txt",
+        self::pixiv::search::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/search.html",
+        self::pixiv::search::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/novel.txt",
+        self::pixiv::novel::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/novel.html",
+        self::pixiv::novel::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/ranking.txt",
+        self::pixiv::ranking::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/ranking.html",
+        self::pixiv::ranking::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/withheld.txt",
+        self::pixiv::withheld::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/pixiv/withheld.html",
+        self::pixiv::withheld::html::default,
+    ),
+    (
         "[fuuka-bot]/templates/bilibili/video.txt",
-        self::bilibili::video::text::default(),
-    )
-    .unwrap();
-    env.add_template(
+        self::bilibili::video::text::default,
+    ),
+    (
         "[fuuka-bot]/templates/bilibili/video.html",
-        self::bilibili::video::html::default(),
-    )
-    .unwrap();
+        self::bilibili::video::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/user.txt",
+        self::bilibili::user::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/user.html",
+        self::bilibili::user::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/bangumi.txt",
+        self::bilibili::bangumi::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/bangumi.html",
+        self::bilibili::bangumi::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/live.txt",
+        self::bilibili::live::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/bilibili/live.html",
+        self::bilibili::live::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/repository.txt",
+        self::github::repository::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/repository.html",
+        self::github::repository::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/commit.txt",
+        self::github::commit::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/commit.html",
+        self::github::commit::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/issue.txt",
+        self::github::issue::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/issue.html",
+        self::github::issue::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/release.txt",
+        self::github::release::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/release.html",
+        self::github::release::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/blob.txt",
+        self::github::blob::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/github/blob.html",
+        self::github::blob::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/crates/info.txt",
+        self::crates::info::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/crates/info.html",
+        self::crates::info::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/nixpkgs/pull_request.txt",
+        self::nixpkgs::pull_request::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/nixpkgs/pull_request.html",
+        self::nixpkgs::pull_request::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/matrix/rooms.txt",
+        self::matrix::rooms::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/matrix/rooms.html",
+        self::matrix::rooms::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/matrix/server.txt",
+        self::matrix::server::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/matrix/server.html",
+        self::matrix::server::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/hitokoto/quote.txt",
+        self::hitokoto::quote::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/hitokoto/quote.html",
+        self::hitokoto::quote::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/action.txt",
+        self::jerryxiao::action::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/action.html",
+        self::jerryxiao::action::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/formatted.txt",
+        self::jerryxiao::formatted::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/formatted.html",
+        self::jerryxiao::formatted::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/fortune.txt",
+        self::jerryxiao::fortune::text::default,
+    ),
+    (
+        "[fuuka-bot]/templates/jerryxiao/fortune.html",
+        self::jerryxiao::fortune::html::default,
+    ),
+    (
+        "[fuuka-bot]/templates/locale/zh-CN.jinja",
+        self::locale::zh_cn,
+    ),
+    ("[fuuka-bot]/templates/locale/en.jinja", self::locale::en),
+    ("[fuuka-bot]/templates/locale/ja.jinja", self::locale::ja),
+];
+
+/// Creates the environment used to format messages.
+///
+/// If `overrides` is given, templates in that directory are used in place of
+/// the built-in ones, with the path relative to it being the template name
+/// without the `[fuuka-bot]/templates/` prefix, e.g. `pixiv/illust.html`.
+///
+/// All templates are loaded in advance, so errors in the overrides are caught at startup.
+pub fn environment(overrides: Option<PathBuf>) -> anyhow::Result<Environment<'static>> {
+    let mut env = Environment::new();
+    let overrides = overrides.map(minijinja::path_loader);
+    env.set_loader(move |name| {
+        if let Some(overrides) = &overrides
+            && let Some(path) = name.strip_prefix(PREFIX)
+            && let Some(template) = overrides(path)?
+        {
+            tracing::info!("Using template override for {name}");
+            return Ok(Some(template));
+        }
+
+        Ok(TEMPLATES
+            .iter()
+            .find(|(template, _)| *template == name)
+            .map(|(_, default)| default().to_string()))
+    });
     env.add_filter("to_html", self::filter::to_html);
     env.add_filter("quote", self::filter::quote);
-    env
-});
+
+    for (name, _) in TEMPLATES {
+        env.get_template(name)
+            .with_context(|| format!("Failed to load template {name}"))?;
+    }
+
+    Ok(env)
+}
+
+#[cfg(test)]
+pub static ENVIRONMENT: LazyLock<Environment> = LazyLock::new(|| environment(None).unwrap());
 
 /// Additional filters.
 pub mod filter {
-    /// Content a piece of text to HTML by converting literal newline with HTML `<br>` tag.
-    pub fn to_html(text: &str) -> String {
-        text.replace("\n", "<br/>")
+    use minijinja::Value;
+
+    /// Content a piece of text to HTML by escaping it and converting literal newline with HTML `<br>` tag.
+    ///
+    /// The result is marked as safe, so it's not escaped again.
+    pub fn to_html(text: &str) -> Value {
+        Value::from_safe_string(super::sanitize::escape(text).replace("\n", "<br/>"))
     }
 
     /// Prepend `>` to each line of given text.
@@ -60,3 +290,54 @@ pub mod filter {
         quoted.join("\n")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn environment_uses_overrides() {
+        let dir = std::env::temp_dir().join(format!("fuuka-bot-templates-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("pixiv")).unwrap();
+        std::fs::write(
+            dir.join("pixiv/withheld.txt"),
+            "Withheld: {{ title }} ({{ restriction }})",
+        )
+        .unwrap();
+
+        let env = super::environment(Some(dir.clone()));
+        std::fs::remove_dir_all(&dir).unwrap();
+        let env = env.unwrap();
+
+        let context = super::pixiv::withheld::Context {
+            language: super::locale::Language::En,
+            id: 132235564,
+            title: "新衣装ホタルちゃん",
+            restriction: "R-18",
+        };
+        assert_str_eq!(
+            super::pixiv::withheld::text::format(&env, &context).unwrap(),
+            "Withheld: 新衣装ホタルちゃん (R-18)"
+        );
+        // Templates without overrides are still the built-in ones.
+        assert_str_eq!(
+            super::pixiv::withheld::html::format(&env, &context).unwrap(),
+            super::pixiv::withheld::html::format(&super::ENVIRONMENT, &context).unwrap()
+        );
+    }
+
+    #[test]
+    fn environment_rejects_invalid_overrides() {
+        let dir = std::env::temp_dir().join(format!(
+            "fuuka-bot-invalid-templates-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("pixiv")).unwrap();
+        std::fs::write(dir.join("pixiv/withheld.txt"), "{{ title").unwrap();
+
+        let env = super::environment(Some(dir.clone()));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(env.is_err());
+    }
+}
@@ -0,0 +1 @@
+pub mod pull_request;
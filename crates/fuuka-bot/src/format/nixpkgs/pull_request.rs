@@ -0,0 +1,147 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub number: i32,
+    pub title: &'a str,
+    pub url: &'a str,
+    /// Whether the pull request is going to be tracked.
+    pub tracking: bool,
+    pub branches: &'a [Branch<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Branch<'a> {
+    pub name: &'a str,
+    /// Whether the pull request has reached this branch.
+    pub merged: bool,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/nixpkgs/pull_request.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("pull_request.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/nixpkgs/pull_request.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("pull_request.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Branch, Context};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            number: 400000,
+            title: "hello: 2.12.1 -> 2.12.2",
+            url: "https://github.com/NixOS/nixpkgs/pull/400000",
+            tracking: true,
+            branches: &[
+                Branch {
+                    name: "master",
+                    merged: true,
+                },
+                Branch {
+                    name: "nixos-unstable",
+                    merged: false,
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>Tracking ",
+                "<a href=\"https://github.com/NixOS/nixpkgs/pull/400000\">PR #400000: hello: 2.12.1 -&gt; 2.12.2</a>",
+                "</p>\n",
+                "<p><b>master</b> ✅<br/><del>nixos-unstable</del></p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            number: 400000,
+            title: "hello: 2.12.1 -> 2.12.2",
+            url: "https://github.com/NixOS/nixpkgs/pull/400000",
+            tracking: true,
+            branches: &[
+                Branch {
+                    name: "master",
+                    merged: true,
+                },
+                Branch {
+                    name: "nixos-unstable",
+                    merged: false,
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "Tracking PR #400000: hello: 2.12.1 -> 2.12.2 https://github.com/NixOS/nixpkgs/pull/400000",
+                "\n",
+                "master ✅",
+                "\n",
+                "nixos-unstable -",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_without_branches() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            number: 400000,
+            title: "hello: 2.12.1 -> 2.12.2",
+            url: "https://github.com/NixOS/nixpkgs/pull/400000",
+            tracking: false,
+            branches: &[],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            "PR #400000: hello: 2.12.1 -> 2.12.2 https://github.com/NixOS/nixpkgs/pull/400000"
+        );
+    }
+}
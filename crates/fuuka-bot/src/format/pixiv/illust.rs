@@ -1,10 +1,29 @@
+use crate::format::locale::Language;
+
 #[derive(serde::Serialize)]
 pub struct Context<'a> {
+    pub language: Language,
     pub id: u64,
     pub title: &'a str,
+    /// The description converted to plain text.
+    pub description: &'a str,
     pub tags: &'a [Tag<'a>],
     pub author: Author<'a>,
     pub triggers: &'a [&'a str],
+    /// Whether to label the illust as AI-generated.
+    pub ai: bool,
+    /// One of `unknown`, `not-ai` or `ai`.
+    pub ai_type: &'a str,
+    /// One of `General`, `R-18` or `R-18G`.
+    pub restriction: &'a str,
+    /// The creation date in RFC 3339.
+    pub create_date: &'a str,
+    pub page_count: u64,
+    pub bookmark_count: u64,
+    pub like_count: u64,
+    pub view_count: u64,
+    /// How many pages are attached, if not all of them are.
+    pub shown_pages: Option<usize>,
 }
 
 #[derive(serde::Serialize)]
@@ -50,31 +69,19 @@ pub mod html {
 #[cfg(test)]
 mod tests {
     use super::{Author, Context, Tag};
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
     use minijinja::Environment;
-    use std::sync::LazyLock;
-
-    static ENVIRONMENT: LazyLock<Environment> = LazyLock::new(|| {
-        let mut env = Environment::new();
-        env.add_template(
-            "[fuuka-bot]/templates/pixiv/illust.txt",
-            super::text::default(),
-        )
-        .unwrap();
-        env.add_template(
-            "[fuuka-bot]/templates/pixiv/illust.html",
-            super::html::default(),
-        )
-        .unwrap();
-        env
-    });
 
     #[test]
     fn format_html_test() {
         use html_compare_rs::assert_html_eq;
 
         let context: Context<'static> = Context {
+            language: Language::ZhCn,
             id: 132235564,
             title: "新衣装ホタルちゃん",
+            description: "",
             tags: &[
                 Tag {
                     original: "ホタル(スターレイル)",
@@ -122,6 +129,15 @@ mod tests {
                 name: "どどうさこ",
             },
             triggers: &[],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
         };
 
         let result = super::html::format(&ENVIRONMENT, &context).unwrap();
@@ -155,8 +171,10 @@ mod tests {
         use pretty_assertions::assert_str_eq;
 
         let context: Context<'static> = Context {
+            language: Language::ZhCn,
             id: 132235564,
             title: "新衣装ホタルちゃん",
+            description: "",
             tags: &[
                 Tag {
                     original: "ホタル(スターレイル)",
@@ -204,6 +222,15 @@ mod tests {
                 name: "どどうさこ",
             },
             triggers: &[],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
         };
 
         let result = super::text::format(&ENVIRONMENT, &context).unwrap();
@@ -234,8 +261,10 @@ mod tests {
         use html_compare_rs::assert_html_eq;
 
         let context: Context<'static> = Context {
+            language: Language::ZhCn,
             id: 132235564,
             title: "新衣装ホタルちゃん",
+            description: "",
             tags: &[
                 Tag {
                     original: "ホタル(スターレイル)",
@@ -283,6 +312,15 @@ mod tests {
                 name: "どどうさこ",
             },
             triggers: &["流萤", "星核猎手"],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
         };
 
         let result = super::html::format(&ENVIRONMENT, &context).unwrap();
@@ -319,8 +357,10 @@ mod tests {
         use pretty_assertions::assert_str_eq;
 
         let context: Context<'static> = Context {
+            language: Language::ZhCn,
             id: 132235564,
             title: "新衣装ホタルちゃん",
+            description: "",
             tags: &[
                 Tag {
                     original: "ホタル(スターレイル)",
@@ -368,6 +408,15 @@ mod tests {
                 name: "どどうさこ",
             },
             triggers: &["流萤", "星核猎手"],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
         };
 
         let result = super::text::format(&ENVIRONMENT, &context).unwrap();
@@ -394,4 +443,185 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn format_html_test_with_ai_label() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            id: 132235564,
+            title: "新衣装ホタルちゃん",
+            description: "",
+            tags: &[Tag {
+                original: "Firefly",
+                translated: None,
+            }],
+            author: Author {
+                id: 78951133,
+                name: "どどうさこ",
+            },
+            triggers: &[],
+            ai: true,
+            ai_type: "ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<font color=\"#d72b6d\"><b>[AI]</b></font> ",
+                "<a href=\"https://www.pixiv.net/artworks/132235564\">新衣装ホタルちゃん</a>",
+                " | ",
+                "<a href=\"https://www.pixiv.net/u/78951133\">@どどうさこ</a>",
+                "</p>\n",
+                "<p>",
+                "<font color=\"#3771bb\">#Firefly</font>",
+                "</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_with_ai_label() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            id: 132235564,
+            title: "新衣装ホタルちゃん",
+            description: "",
+            tags: &[Tag {
+                original: "Firefly",
+                translated: None,
+            }],
+            author: Author {
+                id: 78951133,
+                name: "どどうさこ",
+            },
+            triggers: &[],
+            ai: true,
+            ai_type: "ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 1,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[AI] 新衣装ホタルちゃん https://www.pixiv.net/artworks/132235564",
+                " | ",
+                "@どどうさこ https://www.pixiv.net/u/78951133",
+                "\n",
+                "#Firefly",
+            )
+        );
+    }
+
+    #[test]
+    fn format_custom_template_with_details() {
+        use pretty_assertions::assert_str_eq;
+
+        let mut env = Environment::new();
+        env.add_template(
+            "[fuuka-bot]/templates/pixiv/illust.txt",
+            concat!(
+                "{{ title }} [{{ restriction }}] ({{ page_count }}P, {{ ai_type }})\n",
+                "{{ bookmark_count }} bookmarks, {{ like_count }} likes, {{ view_count }} views\n",
+                "Created at {{ create_date }}\n",
+                "{{ description }}",
+            ),
+        )
+        .unwrap();
+
+        let context: Context<'static> = Context {
+            language: Language::ZhCn,
+            id: 132235564,
+            title: "新衣装ホタルちゃん",
+            description: "ホタルちゃん\nhttps://example.com",
+            tags: &[],
+            author: Author {
+                id: 78951133,
+                name: "どどうさこ",
+            },
+            triggers: &[],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 3,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: None,
+        };
+
+        let result = super::text::format(&env, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "新衣装ホタルちゃん [General] (3P, not-ai)\n",
+                "1024 bookmarks, 512 likes, 8192 views\n",
+                "Created at 2025-06-21T15:00:00+00:00\n",
+                "ホタルちゃん\nhttps://example.com",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_with_shown_pages() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            id: 132235564,
+            title: "新衣装ホタルちゃん",
+            description: "",
+            tags: &[],
+            author: Author {
+                id: 78951133,
+                name: "どどうさこ",
+            },
+            triggers: &[],
+            ai: false,
+            ai_type: "not-ai",
+            restriction: "General",
+            create_date: "2025-06-21T15:00:00+00:00",
+            page_count: 24,
+            bookmark_count: 1024,
+            like_count: 512,
+            view_count: 8192,
+            shown_pages: Some(10),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "新衣装ホタルちゃん https://www.pixiv.net/artworks/132235564",
+                " | ",
+                "@どどうさこ https://www.pixiv.net/u/78951133",
+                "\n\n",
+                "Showing 10 of 24 pages",
+            )
+        );
+    }
 }
@@ -1 +1,6 @@
 pub mod illust;
+pub mod novel;
+pub mod ranking;
+pub mod search;
+pub mod user;
+pub mod withheld;
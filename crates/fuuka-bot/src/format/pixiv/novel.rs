@@ -0,0 +1,251 @@
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub id: u64,
+    pub title: &'a str,
+    pub tags: &'a [Tag<'a>],
+    pub author: Author<'a>,
+    pub series: Option<Series<'a>>,
+    pub word_count: u64,
+    /// The beginning of the text.
+    pub excerpt: Option<&'a str>,
+}
+
+pub use super::illust::{Author, Tag};
+
+#[derive(serde::Serialize)]
+pub struct Series<'a> {
+    pub id: u64,
+    pub title: &'a str,
+    /// The position of the novel in the series, starting from 1.
+    pub order: u64,
+    /// How many novels are in the series, if known.
+    pub count: Option<u64>,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/novel.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("novel.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/novel.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("novel.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Author, Context, Series, Tag};
+
+    use crate::format::ENVIRONMENT;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            id: 20000001,
+            title: "春の夜の夢",
+            tags: &[
+                Tag {
+                    original: "オリジナル",
+                    translated: Some("original"),
+                },
+                Tag {
+                    original: "百合",
+                    translated: None,
+                },
+            ],
+            author: Author {
+                id: 11,
+                name: "pixiv事務局",
+            },
+            series: None,
+            word_count: 12345,
+            excerpt: Some("桜の花が散る夜、\n彼女は<そこ>にいた。"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://www.pixiv.net/novel/show.php?id=20000001\">春の夜の夢</a>",
+                " | ",
+                "<a href=\"https://www.pixiv.net/users/11\">@pixiv事務局</a>",
+                "</p>\n",
+                "<p>",
+                "<font color=\"#3771bb\">#オリジナル</font> (original) ",
+                "<font color=\"#3771bb\">#百合</font>",
+                "</p>\n",
+                "<p>📝 12345</p>",
+                "<blockquote>桜の花が散る夜、<br/>彼女は&lt;そこ&gt;にいた。</blockquote>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_html_test_with_series() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            id: 20000001,
+            title: "春の夜の夢",
+            tags: &[
+                Tag {
+                    original: "オリジナル",
+                    translated: Some("original"),
+                },
+                Tag {
+                    original: "百合",
+                    translated: None,
+                },
+            ],
+            author: Author {
+                id: 11,
+                name: "pixiv事務局",
+            },
+            series: Some(Series {
+                id: 1000001,
+                title: "夢の続き",
+                order: 3,
+                count: Some(12),
+            }),
+            word_count: 12345,
+            excerpt: Some("桜の花が散る夜、\n彼女は<そこ>にいた。"),
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://www.pixiv.net/novel/show.php?id=20000001\">春の夜の夢</a>",
+                " | ",
+                "<a href=\"https://www.pixiv.net/users/11\">@pixiv事務局</a>",
+                "</p>",
+                "<p>📚 <a href=\"https://www.pixiv.net/novel/series/1000001\">夢の続き</a> #3 / 12</p>",
+                "<p>",
+                "<font color=\"#3771bb\">#オリジナル</font> (original) ",
+                "<font color=\"#3771bb\">#百合</font>",
+                "</p>\n",
+                "<p>📝 12345</p>",
+                "<blockquote>桜の花が散る夜、<br/>彼女は&lt;そこ&gt;にいた。</blockquote>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            id: 20000001,
+            title: "春の夜の夢",
+            tags: &[
+                Tag {
+                    original: "オリジナル",
+                    translated: Some("original"),
+                },
+                Tag {
+                    original: "百合",
+                    translated: None,
+                },
+            ],
+            author: Author {
+                id: 11,
+                name: "pixiv事務局",
+            },
+            series: None,
+            word_count: 12345,
+            excerpt: Some("桜の花が散る夜、\n彼女は<そこ>にいた。"),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "春の夜の夢 https://www.pixiv.net/novel/show.php?id=20000001 | @pixiv事務局 https://www.pixiv.net/users/11",
+                "\n",
+                "#オリジナル (original) #百合",
+                "\n",
+                "📝 12345",
+                "\n",
+                "> 桜の花が散る夜、",
+                "\n",
+                "> 彼女は<そこ>にいた。",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_with_series() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            id: 20000001,
+            title: "春の夜の夢",
+            tags: &[
+                Tag {
+                    original: "オリジナル",
+                    translated: Some("original"),
+                },
+                Tag {
+                    original: "百合",
+                    translated: None,
+                },
+            ],
+            author: Author {
+                id: 11,
+                name: "pixiv事務局",
+            },
+            series: Some(Series {
+                id: 1000001,
+                title: "夢の続き",
+                order: 3,
+                count: Some(12),
+            }),
+            word_count: 12345,
+            excerpt: Some("桜の花が散る夜、\n彼女は<そこ>にいた。"),
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "春の夜の夢 https://www.pixiv.net/novel/show.php?id=20000001 | @pixiv事務局 https://www.pixiv.net/users/11",
+                "\n",
+                "📚 夢の続き #3 / 12 https://www.pixiv.net/novel/series/1000001",
+                "\n",
+                "#オリジナル (original) #百合",
+                "\n",
+                "📝 12345",
+                "\n",
+                "> 桜の花が散る夜、",
+                "\n",
+                "> 彼女は<そこ>にいた。",
+            )
+        );
+    }
+}
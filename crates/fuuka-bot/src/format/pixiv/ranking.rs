@@ -0,0 +1,211 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    /// The name of the ranking mode.
+    pub mode: &'a str,
+    /// The name of the type of works.
+    pub content: &'a str,
+    /// The date of the ranking in `YYYYMMDD` format, [None] for the latest one.
+    pub date: Option<&'a str>,
+    pub items: &'a [Item<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Item<'a> {
+    /// The rank of the item, starting from 1.
+    pub rank: u64,
+    pub id: u64,
+    pub title: &'a str,
+    pub tags: &'a [&'a str],
+    pub author: Author<'a>,
+    /// Whether to label the item as AI-generated.
+    pub ai: bool,
+}
+
+pub use super::search::Author;
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/ranking.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("ranking.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/ranking.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("ranking.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Author, Context, Item};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            mode: "Weekly",
+            content: "Manga",
+            date: None,
+            items: &[
+                Item {
+                    rank: 6,
+                    id: 128456789,
+                    title: "夏の日",
+                    tags: &["オリジナル", "漫画"],
+                    author: Author {
+                        id: 11,
+                        name: "pixiv事務局",
+                    },
+                    ai: false,
+                },
+                Item {
+                    rank: 7,
+                    id: 127654321,
+                    title: "Summer <3",
+                    tags: &[],
+                    author: Author {
+                        id: 12,
+                        name: "someone",
+                    },
+                    ai: false,
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p><b>Pixiv Ranking: (Manga/Weekly)</b></p>",
+                "<p>#6: <a href=\"https://www.pixiv.net/artworks/128456789\">夏の日</a>",
+                " | <a href=\"https://www.pixiv.net/users/11\">@pixiv事務局</a>",
+                " | <font color=\"#3771bb\">#オリジナル</font> <font color=\"#3771bb\">#漫画</font></p>",
+                "<p>#7: <a href=\"https://www.pixiv.net/artworks/127654321\">Summer &lt;3</a>",
+                " | <a href=\"https://www.pixiv.net/users/12\">@someone</a></p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            mode: "Weekly",
+            content: "Manga",
+            date: None,
+            items: &[
+                Item {
+                    rank: 6,
+                    id: 128456789,
+                    title: "夏の日",
+                    tags: &["オリジナル", "漫画"],
+                    author: Author {
+                        id: 11,
+                        name: "pixiv事務局",
+                    },
+                    ai: false,
+                },
+                Item {
+                    rank: 7,
+                    id: 127654321,
+                    title: "Summer <3",
+                    tags: &[],
+                    author: Author {
+                        id: 12,
+                        name: "someone",
+                    },
+                    ai: false,
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "Pixiv Ranking: (Manga/Weekly)",
+                "\n",
+                "#6: 夏の日 https://www.pixiv.net/artworks/128456789 | @pixiv事務局 | #オリジナル #漫画",
+                "\n",
+                "#7: Summer <3 https://www.pixiv.net/artworks/127654321 | @someone",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_with_date() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            mode: "Weekly",
+            content: "Manga",
+            date: Some("20260101"),
+            items: &[
+                Item {
+                    rank: 6,
+                    id: 128456789,
+                    title: "夏の日",
+                    tags: &["オリジナル", "漫画"],
+                    author: Author {
+                        id: 11,
+                        name: "pixiv事務局",
+                    },
+                    ai: false,
+                },
+                Item {
+                    rank: 7,
+                    id: 127654321,
+                    title: "Summer <3",
+                    tags: &[],
+                    author: Author {
+                        id: 12,
+                        name: "someone",
+                    },
+                    ai: false,
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "Pixiv Ranking: (Manga/Weekly, 20260101)",
+                "\n",
+                "#6: 夏の日 https://www.pixiv.net/artworks/128456789 | @pixiv事務局 | #オリジナル #漫画",
+                "\n",
+                "#7: Summer <3 https://www.pixiv.net/artworks/127654321 | @someone",
+            )
+        );
+    }
+}
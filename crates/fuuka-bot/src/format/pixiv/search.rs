@@ -0,0 +1,150 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    /// The searched tags.
+    pub word: &'a str,
+    /// The URL of the search page.
+    pub url: &'a str,
+    pub items: &'a [Item<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Item<'a> {
+    pub id: u64,
+    pub title: &'a str,
+    pub author: Author<'a>,
+    /// Whether to label the item as AI-generated.
+    pub ai: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct Author<'a> {
+    pub id: u64,
+    pub name: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/search.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("search.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/search.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("search.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Author, Context, Item};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            word: "ブルーアーカイブ 水着",
+            url: "https://www.pixiv.net/tags/%E3%83%96%E3%83%AB%E3%83%BC%E3%82%A2%E3%83%BC%E3%82%AB%E3%82%A4%E3%83%96%20%E6%B0%B4%E7%9D%80/artworks",
+            items: &[
+                Item {
+                    id: 128456789,
+                    title: "夏の日",
+                    author: Author {
+                        id: 11,
+                        name: "pixiv事務局",
+                    },
+                    ai: false,
+                },
+                Item {
+                    id: 127654321,
+                    title: "Summer <3",
+                    author: Author {
+                        id: 12,
+                        name: "someone",
+                    },
+                    ai: false,
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p><b>Pixiv Search: <a href=\"https://www.pixiv.net/tags/%E3%83%96%E3%83%AB%E3%83%BC%E3%82%A2%E3%83%BC%E3%82%AB%E3%82%A4%E3%83%96%20%E6%B0%B4%E7%9D%80/artworks\">ブルーアーカイブ 水着</a></b></p>",
+                "<ol>",
+                "<li><a href=\"https://www.pixiv.net/artworks/128456789\">夏の日</a> | <a href=\"https://www.pixiv.net/users/11\">@pixiv事務局</a></li>",
+                "<li><a href=\"https://www.pixiv.net/artworks/127654321\">Summer &lt;3</a> | <a href=\"https://www.pixiv.net/users/12\">@someone</a></li>",
+                "</ol>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            word: "ブルーアーカイブ 水着",
+            url: "https://www.pixiv.net/tags/%E3%83%96%E3%83%AB%E3%83%BC%E3%82%A2%E3%83%BC%E3%82%AB%E3%82%A4%E3%83%96%20%E6%B0%B4%E7%9D%80/artworks",
+            items: &[
+                Item {
+                    id: 128456789,
+                    title: "夏の日",
+                    author: Author {
+                        id: 11,
+                        name: "pixiv事務局",
+                    },
+                    ai: false,
+                },
+                Item {
+                    id: 127654321,
+                    title: "Summer <3",
+                    author: Author {
+                        id: 12,
+                        name: "someone",
+                    },
+                    ai: false,
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "Pixiv Search: ブルーアーカイブ 水着 https://www.pixiv.net/tags/%E3%83%96%E3%83%AB%E3%83%BC%E3%82%A2%E3%83%BC%E3%82%AB%E3%82%A4%E3%83%96%20%E6%B0%B4%E7%9D%80/artworks",
+                "\n",
+                "#1: 夏の日 https://www.pixiv.net/artworks/128456789 | @pixiv事務局",
+                "\n",
+                "#2: Summer <3 https://www.pixiv.net/artworks/127654321 | @someone",
+            )
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub id: u64,
+    pub name: &'a str,
+    pub comment: Option<&'a str>,
+    pub illusts_count: usize,
+    pub manga_count: usize,
+    pub novels_count: usize,
+    /// The latest illusts and manga.
+    pub works: &'a [Work<'a>],
+}
+
+#[derive(serde::Serialize)]
+pub struct Work<'a> {
+    pub id: u64,
+    pub title: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/user.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("user.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/user.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("user.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Work};
+
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            id: 11,
+            name: "pixiv事務局",
+            comment: Some("pixiv事務局です。\nよろしくお願いします。"),
+            illusts_count: 120,
+            manga_count: 8,
+            novels_count: 3,
+            works: &[
+                Work {
+                    id: 128456789,
+                    title: "pixivision 10th Anniversary",
+                },
+                Work {
+                    id: 127654321,
+                    title: "Winter Contest <Results>",
+                },
+            ],
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "<a href=\"https://www.pixiv.net/users/11\">@pixiv事務局</a>",
+                "</p>\n",
+                "<p>",
+                "🖼️ 120 · 📖 8 · 📝 3",
+                "</p>",
+                "<blockquote>pixiv事務局です。<br/>よろしくお願いします。</blockquote>",
+                "<p>Latest works:</p>",
+                "<ul>",
+                "<li><a href=\"https://www.pixiv.net/artworks/128456789\">pixivision 10th Anniversary</a></li>",
+                "<li><a href=\"https://www.pixiv.net/artworks/127654321\">Winter Contest &lt;Results&gt;</a></li>",
+                "</ul>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context: Context<'static> = Context {
+            language: Language::En,
+            id: 11,
+            name: "pixiv事務局",
+            comment: Some("pixiv事務局です。\nよろしくお願いします。"),
+            illusts_count: 120,
+            manga_count: 8,
+            novels_count: 3,
+            works: &[
+                Work {
+                    id: 128456789,
+                    title: "pixivision 10th Anniversary",
+                },
+                Work {
+                    id: 127654321,
+                    title: "Winter Contest <Results>",
+                },
+            ],
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "@pixiv事務局 https://www.pixiv.net/users/11",
+                "\n",
+                "🖼️ 120 · 📖 8 · 📝 3",
+                "\n",
+                "> pixiv事務局です。",
+                "\n",
+                "> よろしくお願いします。",
+                "\n",
+                "Latest works:",
+                "\n",
+                "• pixivision 10th Anniversary https://www.pixiv.net/artworks/128456789",
+                "\n",
+                "• Winter Contest <Results> https://www.pixiv.net/artworks/127654321",
+            )
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::format::locale::Language;
+
+#[derive(serde::Serialize)]
+pub struct Context<'a> {
+    pub language: Language,
+    pub id: u64,
+    pub title: &'a str,
+    /// The restriction of the illust, like `R-18`.
+    pub restriction: &'a str,
+}
+
+pub mod text {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/withheld.txt")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("withheld.text.jinja")
+    }
+}
+
+pub mod html {
+    use super::Context;
+    use minijinja::Environment;
+
+    pub fn format<'a>(env: &Environment, context: &'a Context<'a>) -> anyhow::Result<String> {
+        let text = env.get_template("[fuuka-bot]/templates/pixiv/withheld.html")?;
+        Ok(text.render(context)?)
+    }
+
+    pub fn default() -> &'static str {
+        include_str!("withheld.html.jinja")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+    use crate::format::ENVIRONMENT;
+    use crate::format::locale::Language;
+
+    #[test]
+    fn format_html_test() {
+        use html_compare_rs::assert_html_eq;
+
+        let context = Context {
+            language: Language::En,
+            id: 128456789,
+            title: "Summer <3",
+            restriction: "R-18",
+        };
+
+        let result = super::html::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_html_eq!(
+            result,
+            concat!(
+                "<p>",
+                "[R-18] <span data-mx-spoiler=\"R-18\">",
+                "<a href=\"https://www.pixiv.net/artworks/128456789\">Summer &lt;3</a>",
+                "</span>",
+                "</p>\n",
+                "<p>The full image is withheld as R-18 works are not enabled in this room.</p>",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test() {
+        use pretty_assertions::assert_str_eq;
+
+        let context = Context {
+            language: Language::En,
+            id: 128456789,
+            title: "Summer <3",
+            restriction: "R-18G",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[R-18G] Summer <3 https://www.pixiv.net/artworks/128456789\n",
+                "The full image is withheld as R-18G works are not enabled in this room.",
+            )
+        );
+    }
+
+    #[test]
+    fn format_text_test_in_chinese() {
+        use pretty_assertions::assert_str_eq;
+
+        let context = Context {
+            language: Language::ZhCn,
+            id: 128456789,
+            title: "Summer <3",
+            restriction: "R-18",
+        };
+
+        let result = super::text::format(&ENVIRONMENT, &context).unwrap();
+
+        assert_str_eq!(
+            result,
+            concat!(
+                "[R-18] Summer <3 https://www.pixiv.net/artworks/128456789\n",
+                "本房间未启用 R-18 作品，完整图片已隐藏。",
+            )
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Escaping untrusted text for HTML messages, and converting HTML to text.
+//!
+//! Templates ending in `.html` are escaped by [minijinja] automatically,
+//! these are for the few places building HTML without a template.
+use minijinja::HtmlEscape;
+
+/// Escapes the text to be put in HTML.
+pub fn escape(text: &str) -> String {
+    HtmlEscape(text).to_string()
+}
+
+/// Converts HTML to plain text, like Pixiv descriptions and page titles.
+///
+/// Line breaks and paragraphs become newlines, other tags are dropped
+/// and character references are decoded.
+pub fn html_to_text(html: &str) -> String {
+    // Parsing only fails on inputs longer than `u32::MAX`.
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return String::new();
+    };
+    let parser = dom.parser();
+
+    let mut text = String::with_capacity(html.len());
+    for &handle in dom.children() {
+        push_node(&mut text, handle, parser);
+    }
+
+    text.trim().to_string()
+}
+
+/// Pushes the text of the node and its children.
+fn push_node(text: &mut String, handle: tl::NodeHandle, parser: &tl::Parser) {
+    match handle.get(parser) {
+        Some(tl::Node::Tag(tag)) => {
+            let name = tag.name().as_utf8_str();
+            let paragraph = name.eq_ignore_ascii_case("p");
+            if paragraph || name.eq_ignore_ascii_case("br") {
+                text.push('\n');
+            }
+            for &child in tag.children().top().iter() {
+                push_node(text, child, parser);
+            }
+            if paragraph {
+                text.push('\n');
+            }
+        }
+        Some(tl::Node::Raw(raw)) => push_decoded(text, &raw.as_utf8_str()),
+        Some(tl::Node::Comment(_)) | None => {}
+    }
+}
+
+/// Pushes the text with character references decoded.
+fn push_decoded(text: &mut String, mut rest: &str) {
+    while let Some(pos) = rest.find('&') {
+        text.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        let decoded = rest
+            .get(1..)
+            .and_then(|s| s.find(';').filter(|&end| end <= 10).map(|end| (s, end)))
+            .and_then(|(s, end)| decode_reference(&s[..end]).map(|c| (c, end)));
+        match decoded {
+            Some((c, end)) => {
+                text.push(c);
+                rest = &rest[end + 2..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+}
+
+/// Decodes a character reference without the leading `&` and trailing `;`.
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code).filter(|c| !c.is_control() || *c == '\n')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn escape_markup() {
+        assert_eq!(
+            super::escape(r#"<a href="https://matrix.to/#/@admin:example.org">@admin</a> & co"#),
+            "&lt;a href=&quot;https:&#x2f;&#x2f;matrix.to&#x2f;#&#x2f;@admin:example.org&quot;&gt;@admin&lt;&#x2f;a&gt; &amp; co"
+        );
+    }
+
+    #[test]
+    fn html_to_text_converts_html() {
+        let html = concat!(
+            "Commission work &amp; more<br />",
+            "<strong>Tools</strong>: CLIP STUDIO<br /><br />",
+            "Twitter: <a href=\"/jump.php?https%3A%2F%2Fx.com%2Fexample\" target=\"_blank\">",
+            "https://x.com/example</a><br />",
+            "&#39;Quoted&#x27; &quot;text&quot; &lt;3",
+        );
+
+        assert_eq!(
+            super::html_to_text(html),
+            concat!(
+                "Commission work & more\n",
+                "Tools: CLIP STUDIO\n\n",
+                "Twitter: https://x.com/example\n",
+                "'Quoted' \"text\" <3",
+            )
+        );
+    }
+
+    #[test]
+    fn html_to_text_keeps_unknown_references() {
+        assert_eq!(
+            super::html_to_text("a & b &unknown; &#0; c &lt; d"),
+            "a & b &unknown; &#0; c < d"
+        );
+    }
+
+    #[test]
+    fn html_to_text_drops_quoted_attributes() {
+        assert_eq!(
+            super::html_to_text(r#"<a title="a>b" href="/">x</a><p>y</p>"#),
+            "x\ny"
+        );
+    }
+}
@@ -27,6 +27,7 @@ use crate::config::FeaturesConfig;
 use crate::config::GitHubConfig;
 use crate::config::MediaProxyConfig;
 use crate::config::PixivConfig;
+use crate::format::locale::Language;
 pub use crate::media_proxy::MediaProxy;
 use crate::services::github::pr_tracker::streams::CronStream;
 pub use crate::traits::*;
@@ -36,6 +37,7 @@ use matrix_sdk::authentication::matrix::MatrixSession;
 use matrix_sdk::config::RequestConfig;
 use matrix_sdk::config::SyncSettings;
 use matrix_sdk::ruma::OwnedUserId;
+use matrix_sdk::ruma::RoomId;
 use matrix_sdk::ruma::presence::PresenceState;
 use pixiv_ajax_api::PixivClient;
 use std::str::FromStr;
@@ -68,10 +70,46 @@ pub struct Context {
     pub http: reqwest::Client,
     pub hitokoto: hitokoto_api::HitokotoClient,
     pub crates: crates_api::CratesClient,
+    pub bilibili: bilibili_api::BiliBiliClient,
     pub media_proxy: Option<MediaProxy>,
     pub pixiv: Option<(Arc<PixivClient>, Arc<crate::services::pixiv::Context>)>,
     pub features: FeaturesConfig,
     pub github: Option<crate::services::github::Context>,
+    /// The GitHub client used for link previews, which works without the PR tracker.
+    pub octocrab: octocrab::Octocrab,
+    /// The environment used to format messages.
+    pub templates: Arc<minijinja::Environment<'static>>,
+    /// The language of replies in rooms without one set.
+    pub language: Language,
+}
+
+impl Context {
+    /// Returns the language of replies in the room.
+    pub fn language(&self, room_id: &RoomId) -> Language {
+        self.features
+            .room_language(room_id)
+            .unwrap_or(self.language)
+    }
+
+    /// Returns the messages in the language of the room.
+    pub fn messages(
+        &self,
+        room_id: &RoomId,
+    ) -> anyhow::Result<crate::format::locale::Messages<'_>> {
+        crate::format::locale::Messages::new(&self.templates, self.language(room_id))
+    }
+
+    /// Renders the message `name` in the language of the room.
+    ///
+    /// See [crate::format::locale::message] for details.
+    pub fn message(
+        &self,
+        room_id: &RoomId,
+        name: &str,
+        args: &[minijinja::Value],
+    ) -> anyhow::Result<String> {
+        crate::format::locale::message(&self.templates, self.language(room_id), name, args)
+    }
 }
 
 #[derive(Debug, clap::Parser)]
@@ -131,6 +169,9 @@ impl Builder {
 
         let config: Config = crate::env::config().context("Getting config failed!")?;
 
+        let templates = crate::format::environment(crate::env::templates().ok())
+            .context("Loading templates failed!")?;
+
         let http = reqwest::Client::builder()
             .user_agent(APP_USER_AGENT)
             .build()?;
@@ -148,6 +189,7 @@ impl Builder {
             session,
             http,
             builder,
+            templates,
             with_key_backups: self.with_key_backups,
             with_optional_media_proxy: self.with_optional_media_proxy,
         })
@@ -160,6 +202,7 @@ pub struct Client {
     session: MatrixSession,
     http: reqwest::Client,
     builder: matrix_sdk::ClientBuilder,
+    templates: minijinja::Environment<'static>,
     with_key_backups: bool,
     with_optional_media_proxy: bool,
 }
@@ -172,6 +215,7 @@ impl Client {
             session,
             http,
             builder,
+            templates,
             with_key_backups,
             with_optional_media_proxy,
         } = self;
@@ -213,12 +257,17 @@ impl Client {
             media_proxy.start().await?;
         }
 
+        let mut digests = Vec::new();
+        let mut session_check_interval = None;
         let pixiv = match config.pixiv {
             PixivConfig::Disabled => None,
             PixivConfig::Enabled {
                 token,
                 r18,
                 tag_triggers,
+                digests: digest_configs,
+                max_upload_size,
+                session_check_interval: interval,
             } => {
                 use http_body_util::BodyExt;
                 use tower::BoxError;
@@ -235,7 +284,21 @@ impl Client {
                     .layer(crate::middleware::reqwest::ReqwestLayer)
                     .service(http.clone());
                 let client = Arc::new(PixivClient::new(service, token));
-                let context = crate::services::pixiv::Context { r18, tag_triggers };
+                digests = digest_configs
+                    .into_iter()
+                    .map(|digest| {
+                        let send_r18 = r18 && config.features.room_pixiv_r18_enabled(&digest.room);
+                        let ai_filter = config.features.room_pixiv_ai_filter(&digest.room);
+                        (digest, send_r18, ai_filter)
+                    })
+                    .collect();
+                session_check_interval = Some(interval);
+                let context = crate::services::pixiv::Context {
+                    r18,
+                    tag_triggers,
+                    max_upload_size,
+                    session: Default::default(),
+                };
                 Some((client, Arc::new(context)))
             }
         };
@@ -248,16 +311,18 @@ impl Client {
             token,
         } = config.services.github;
 
+        let octocrab = {
+            let base_url = http::Uri::from_str(base_url.as_str())?;
+            crate::services::github::octocrab(&http, base_url, token)
+        };
+
         let github = match pr_tracker {
             config::PrTrackerConfig::Enabled { cron, targets } => {
                 use crate::services::github::pr_tracker::PrTrackerContext;
-                use std::str::FromStr;
-                let base_url = http::Uri::from_str(base_url.as_str())?;
-                let octocrab = crate::services::github::octocrab(&http, base_url, token);
                 let cron = cron.map(CronStream::new).map(Arc::new);
 
                 Some(crate::services::github::Context {
-                    octocrab,
+                    octocrab: octocrab.clone(),
                     cron,
                     pr_tracker: Arc::new(PrTrackerContext::new(targets)?),
                 })
@@ -299,6 +364,24 @@ impl Client {
                 .service(http.clone());
             crates_api::CratesClient::new(service, base_url)
         };
+        let bilibili = {
+            use http_body_util::BodyExt;
+            use tower::BoxError;
+            use tower_http::ServiceBuilderExt;
+
+            let base_url = http::Uri::from_static("https://api.bilibili.com");
+            let live_base_url = http::Uri::from_static("https://api.live.bilibili.com");
+            let service = tower::ServiceBuilder::new()
+                .concurrency_limit(1)
+                .rate_limit(1, Duration::from_secs(1))
+                .map_response_body(|resp: reqwest::Body| {
+                    resp.map_err(|e| Into::into(Box::new(e) as BoxError))
+                        .boxed()
+                })
+                .layer(crate::middleware::reqwest::ReqwestLayer)
+                .service(http.clone());
+            bilibili_api::BiliBiliClient::new(service, base_url, live_base_url)
+        };
 
         let context = Context {
             prefix,
@@ -306,12 +389,41 @@ impl Client {
             pixiv,
             media_proxy,
             github,
+            octocrab,
             features: config.features,
             hitokoto,
             crates,
+            bilibili,
             admin_user: config.admin_user,
+            templates: Arc::new(templates),
+            language: config.language,
         };
 
+        if let Some((pixiv, pixiv_context)) = &context.pixiv {
+            if let Some(interval) = session_check_interval {
+                tokio::spawn(crate::services::pixiv::session::run(
+                    client.clone(),
+                    pixiv.clone(),
+                    pixiv_context.clone(),
+                    context.clone(),
+                    interval,
+                ));
+            }
+            for (digest, send_r18, ai_filter) in digests {
+                let language = context.language(&digest.room);
+                tokio::spawn(crate::services::pixiv::digest::run(
+                    client.clone(),
+                    pixiv.clone(),
+                    context.http.clone(),
+                    context.templates.clone(),
+                    language,
+                    digest,
+                    send_r18,
+                    ai_filter,
+                ));
+            }
+        }
+
         client.add_event_handler_context(context);
         crate::matrix::log_encryption_info(&client).await?;
         let task: JoinHandle<()> = tokio::spawn(async move {
@@ -0,0 +1,112 @@
+//! Reuses uploaded media with the same content.
+//!
+//! Only unencrypted uploads can be reused, as attachments in encrypted rooms
+//! are encrypted with a new key every time. Pixiv illusts, both single images
+//! and galleries, are sent with [upload_cached] in unencrypted rooms, while
+//! illusts in encrypted rooms, ugoira and other attachments are still uploaded
+//! every time.
+use std::time::Duration;
+
+use jose_jwk::jose_b64::base64ct::{Base64UrlUnpadded, Encoding};
+use matrix_sdk::ruma::{MilliSecondsSinceUnixEpoch, OwnedMxcUri};
+use mime::Mime;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Prefix of the keys in the state store.
+const KEY_PREFIX: &str = "fuuka_bot.media_cache.";
+
+/// How long an upload is reused, so uploads purged by the media retention
+/// of the homeserver are uploaded again.
+const TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// A previous upload, stored as JSON in the state store.
+#[derive(Serialize, Deserialize, Debug)]
+struct CachedUpload {
+    uri: OwnedMxcUri,
+    uploaded_at: MilliSecondsSinceUnixEpoch,
+}
+
+impl CachedUpload {
+    fn is_expired(&self, now: MilliSecondsSinceUnixEpoch) -> bool {
+        let age = u64::from(now.get()).saturating_sub(self.uploaded_at.get().into());
+        age >= TTL.as_millis() as u64
+    }
+}
+
+/// Uploads `data` unencrypted, reusing the URI of a previous upload with the same content.
+///
+/// Uploads are keyed by the SHA-256 of the content in the state store,
+/// and are uploaded again once they are older than [TTL].
+pub(crate) async fn upload_cached(
+    client: &matrix_sdk::Client,
+    content_type: &Mime,
+    data: Vec<u8>,
+) -> anyhow::Result<OwnedMxcUri> {
+    let key = cache_key(&data);
+    let store = client.state_store();
+
+    if let Some(value) = store.get_custom_value(key.as_bytes()).await? {
+        match serde_json::from_slice::<CachedUpload>(&value) {
+            Ok(cached) if !cached.uri.is_valid() => {
+                tracing::warn!("Ignoring the invalid cached upload of {key}")
+            }
+            Ok(cached) if cached.is_expired(MilliSecondsSinceUnixEpoch::now()) => {
+                tracing::debug!("The previous upload {uri} has expired", uri = cached.uri)
+            }
+            Ok(cached) => {
+                tracing::debug!("Reusing the previous upload {uri}", uri = cached.uri);
+                return Ok(cached.uri);
+            }
+            Err(e) => tracing::warn!("Ignoring the invalid cached upload of {key}: {e}"),
+        }
+    }
+
+    let response = client.media().upload(content_type, data, None).await?;
+    let cached = CachedUpload {
+        uri: response.content_uri,
+        uploaded_at: MilliSecondsSinceUnixEpoch::now(),
+    };
+    store
+        .set_custom_value(key.as_bytes(), serde_json::to_vec(&cached)?)
+        .await?;
+
+    Ok(cached.uri)
+}
+
+fn cache_key(data: &[u8]) -> String {
+    let hash = Base64UrlUnpadded::encode_string(&Sha256::digest(data));
+    format!("{KEY_PREFIX}{hash}")
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix_sdk::ruma::{MilliSecondsSinceUnixEpoch, UInt, owned_mxc_uri};
+    use pretty_assertions::assert_eq;
+
+    use super::{CachedUpload, TTL, cache_key};
+
+    #[test]
+    fn cache_key_is_hash_of_content() {
+        assert_eq!(
+            cache_key(b""),
+            "fuuka_bot.media_cache.47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU"
+        );
+        assert_eq!(cache_key(b"image"), cache_key(b"image"));
+        assert_ne!(cache_key(b"image"), cache_key(b"other image"));
+    }
+
+    #[test]
+    fn cached_upload_expires_after_ttl() {
+        let at = |millis: u64| MilliSecondsSinceUnixEpoch(UInt::new(millis).unwrap());
+        let cached = CachedUpload {
+            uri: owned_mxc_uri!("mxc://example.org/media"),
+            uploaded_at: at(1_000),
+        };
+        let ttl = TTL.as_millis() as u64;
+
+        assert!(!cached.is_expired(at(1_000)));
+        assert!(!cached.is_expired(at(1_000 + ttl - 1)));
+        assert!(cached.is_expired(at(1_000 + ttl)));
+    }
+}
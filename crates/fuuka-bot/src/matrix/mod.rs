@@ -1,4 +1,5 @@
 pub mod federation;
+pub mod media;
 pub mod streams;
 
 use anyhow::Context;
@@ -7,23 +7,9 @@ use matrix_sdk::{
     },
 };
 
-static HELP_TEXT: &str = concat!(
-    "Fuuka Bot\n\nSource: ",
-    env!("CARGO_PKG_REPOSITORY"),
-    "\nCommands: https://shadowrz.github.io/fuuka-bot/commands.html",
-    "\nSend a feature request: ",
-    env!("CARGO_PKG_REPOSITORY"),
-    "/issues",
-);
-
-static HELP_HTML: &str = concat!(
-    "<p>Fuuka Bot</p><p>Source: ",
-    env!("CARGO_PKG_REPOSITORY"),
-    "<br/>Commands: https://shadowrz.github.io/fuuka-bot/commands.html",
-    "<br/>Send a feature request: ",
-    env!("CARGO_PKG_REPOSITORY"),
-    "/issues</p>",
-);
+const SOURCE_URL: &str = env!("CARGO_PKG_REPOSITORY");
+const COMMANDS_URL: &str = "https://shadowrz.github.io/fuuka-bot/commands.html";
+const ISSUES_URL: &str = concat!(env!("CARGO_PKG_REPOSITORY"), "/issues");
 
 #[tracing::instrument(name = "help", skip(ev, room, context), err)]
 pub async fn process(
@@ -31,10 +17,24 @@ pub async fn process(
     room: &Room,
     context: &Ctx<Context>,
 ) -> anyhow::Result<()> {
-    let _ = context;
+    let messages = context.messages(room.room_id())?;
+    let lines = [
+        messages.message("about_source", &[SOURCE_URL.into()])?,
+        messages.message("about_commands", &[COMMANDS_URL.into()])?,
+        messages.message("about_feature_request", &[ISSUES_URL.into()])?,
+    ];
+    let mut body = format!("Fuuka Bot\n\n{}", lines.join("\n"));
+    let mut html_body = format!("<p>Fuuka Bot</p><p>{}</p>", lines.join("<br/>"));
+    if let Some((_, pixiv_context)) = &context.pixiv {
+        let status = *pixiv_context.session.read();
+        let status = status.message(&messages)?;
+        let pixiv = messages.message("about_pixiv", &[status.into()])?;
+        body.push_str(&format!("\n{pixiv}"));
+        html_body.push_str(&format!("<p>{pixiv}</p>"));
+    }
 
     room.send(
-        RoomMessageEventContent::text_html(HELP_TEXT, HELP_HTML).make_reply_to(
+        RoomMessageEventContent::text_html(body, html_body).make_reply_to(
             ev,
             ForwardThread::No,
             AddMentions::Yes,
@@ -1,5 +1,5 @@
 use crate::Context;
-use anyhow::Context as _;
+use crate::services::bilibili::resource::Resource;
 use matrix_sdk::{
     Room,
     event_handler::Ctx,
@@ -13,13 +13,21 @@ pub async fn process(
     context: &Ctx<Context>,
     id: &str,
 ) -> anyhow::Result<()> {
-    let video = crate::services::bilibili::video::request(&context.http, id)
-        .await
-        .context(format!("Failed to query BiliBili video {id}"))?;
-    let content = crate::services::bilibili::video::format(video, false)?;
-
-    room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
-        .await?;
+    let resource: Resource = id.parse()?;
+    if let Some(content) = crate::services::bilibili::lookup(
+        ev,
+        room,
+        &context.bilibili,
+        &context.http,
+        &context.templates,
+        context.language(room.room_id()),
+        resource,
+    )
+    .await?
+    {
+        room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
+            .await?;
+    }
 
     Ok(())
 }
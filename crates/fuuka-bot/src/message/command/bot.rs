@@ -34,7 +34,7 @@ pub async fn process(
     match command {
         BotCommand::SetAvatar => {
             let Some(ev) = room.in_reply_to_event(ev).await? else {
-                room.send_requires_reply().await?;
+                room.send_requires_reply(context).await?;
                 return Ok(());
             };
 
@@ -61,11 +61,10 @@ pub async fn process(
                         let format = FileFormat::from_bytes(&data);
                         let mimetype = format.media_type();
 
-                        let response = media.upload(&mimetype.parse()?, data, None).await?;
-                        client
-                            .account()
-                            .set_avatar_url(Some(&response.content_uri))
-                            .await?;
+                        let uri =
+                            crate::matrix::media::upload_cached(&client, &mimetype.parse()?, data)
+                                .await?;
+                        client.account().set_avatar_url(Some(&uri)).await?;
                     }
                 }
             } else {
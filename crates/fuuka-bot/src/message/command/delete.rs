@@ -7,10 +7,8 @@ pub async fn process(
     room: &Room,
     context: &Ctx<Context>,
 ) -> anyhow::Result<()> {
-    let _ = context;
-
     let Some(ev) = room.in_reply_to_event(ev).await? else {
-        room.send_requires_reply().await?;
+        room.send_requires_reply(context).await?;
         return Ok(());
     };
 
@@ -15,12 +15,16 @@ pub async fn process(
     room: &Room,
     context: &Ctx<Context>,
 ) -> anyhow::Result<()> {
-    let Ctx(Context { hitokoto, .. }) = context;
+    let Ctx(Context {
+        hitokoto,
+        templates,
+        ..
+    }) = context;
     let resp = hitokoto
         .request(BTreeSet::new())
         .await
         .context("Failed to request hitokoto")?;
-    let content = crate::services::hitokoto::format(resp);
+    let content = crate::services::hitokoto::format(templates, resp)?;
     room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
         .await?;
 
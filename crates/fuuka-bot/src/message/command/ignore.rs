@@ -26,7 +26,7 @@ pub async fn process(
     }
 
     let Some(user_id) = room.in_reply_to_target(ev).await? else {
-        room.send_requires_reply().await?;
+        room.send_requires_reply(context).await?;
         return Ok(());
     };
 
@@ -34,7 +34,8 @@ pub async fn process(
     let account = room.client().account();
     account.ignore_user(&user_id).await?;
 
-    room.send(RoomMessageEventContent::text_plain("Done.").make_reply_to(
+    let body = context.message(room.room_id(), "done", &[])?;
+    room.send(RoomMessageEventContent::text_plain(body).make_reply_to(
         ev,
         ForwardThread::No,
         AddMentions::Yes,
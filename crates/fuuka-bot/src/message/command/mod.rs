@@ -38,7 +38,12 @@ pub(super) async fn process(
         Args::Hitokoto => self::hitokoto::process(ev, room, context).await,
         Args::Ignore => self::ignore::process(ev, room, context).await,
         Args::Unignore { user_id } => self::unignore::process(ev, room, context, user_id).await,
-        Args::Pixiv { command } => self::pixiv::process(ev, room, context, command).await,
+        Args::Pixiv {
+            subcommand,
+            command,
+            pages,
+            ranking,
+        } => self::pixiv::process(ev, room, context, subcommand, command, pages, ranking).await,
         Args::Nixpkgs { pr_number, what } => {
             let track = what == Some(NixpkgsCommand::Track);
             self::nixpkgs::process(ev, room, context, pr_number, track).await
@@ -22,7 +22,9 @@ pub async fn process(
     pr_number: i32,
     track: bool,
 ) -> anyhow::Result<()> {
-    let Ctx(Context { github, .. }) = context;
+    let Ctx(Context {
+        github, templates, ..
+    }) = context;
 
     let repository = RepositoryParts {
         owner: "NixOS".to_string(),
@@ -69,56 +71,47 @@ pub async fn process(
         }
     };
 
-    let mut in_branches = String::new();
-
-    for (branch, in_branch) in in_branches_data.iter() {
-        in_branches.push_str(&format!(
-            "\n{branch} {compare}",
-            compare = if *in_branch { "✅" } else { "-" }
-        ));
-    }
-
-    let mut in_branches_html = String::new();
+    {
+        use crate::format::nixpkgs::pull_request::{Branch, Context};
+
+        let branches: Vec<_> = in_branches_data
+            .iter()
+            .map(|(name, merged)| Branch {
+                name,
+                merged: *merged,
+            })
+            .collect();
+        let url = format!("https://github.com/NixOS/nixpkgs/pull/{pr_number}");
+        let context = Context {
+            language: context.language(room.room_id()),
+            number: pr_number,
+            title: &result.title,
+            url: &url,
+            tracking: track,
+            branches: &branches,
+        };
 
-    if !in_branches_data.is_empty() {
-        in_branches_html.push_str("<p>");
-        for (branch, in_branch) in in_branches_data.iter() {
-            in_branches_html.push_str(&format!(
-                "<{tag}>{branch}</{tag}>{compare}",
-                tag = if *in_branch { "b" } else { "del" },
-                compare = if *in_branch { " ✅<br/>" } else { "<br/>" }
-            ));
-        }
-        in_branches_html.push_str("</p>");
+        let body = crate::format::nixpkgs::pull_request::text::format(templates, &context)?;
+        let html_body = crate::format::nixpkgs::pull_request::html::format(templates, &context)?;
+
+        room.send(
+            RoomMessageEventContent::text_html(body, html_body).make_reply_to(
+                ev,
+                ForwardThread::No,
+                AddMentions::Yes,
+            ),
+        )
+        .await?;
     }
 
-    room.send(RoomMessageEventContent::text_html(
-        format!(
-            "{track_or_not}PR #{pr_number}: {title} https://github.com/NixOS/nixpkgs/pull/{pr_number}{in_branches}",
-            track_or_not = if track { "Tracking " } else { "" },
-            title = result.title,
-            in_branches = in_branches,
-        ),
-        format!(
-            "<p>{track_or_not}<a href='https://github.com/NixOS/nixpkgs/pull/{pr_number}'>PR #{pr_number}: {title}</a>{in_branches}",
-            track_or_not = if track { "Tracking " } else { "" },
-            title = result.title,
-            in_branches = in_branches_html,
-        ),
-    ).make_reply_to(
-        ev,
-        ForwardThread::No,
-        AddMentions::Yes,
-    )).await?;
-
     if track {
         if !room.is_direct().await? {
-            room.send(
-                RoomMessageEventContent::text_plain(
-                    "Tracking Nixpkgs PR is only avaliable in a DM!",
-                )
-                .make_reply_to(ev, ForwardThread::No, AddMentions::Yes),
-            )
+            let body = context.message(room.room_id(), "nixpkgs_track_dm_only", &[])?;
+            room.send(RoomMessageEventContent::text_plain(body).make_reply_to(
+                ev,
+                ForwardThread::No,
+                AddMentions::Yes,
+            ))
             .await?;
             return Ok(());
         }
@@ -147,14 +140,14 @@ pub async fn process(
             match result {
                 Ok(result) => match result.state {
                     PullRequestState::CLOSED { .. } => {
+                        let body = context.message(
+                            room.room_id(),
+                            "nixpkgs_closed",
+                            &[pr_number.into()],
+                        )?;
                         if let Err(error) = room
                             .send_queue()
-                            .send(
-                                RoomMessageEventContent::text_plain(format!(
-                                    "PR #{pr_number} is closed! 😞"
-                                ))
-                                .into(),
-                            )
+                            .send(RoomMessageEventContent::text_plain(body).into())
                             .await
                         {
                             tracing::warn!(
@@ -165,14 +158,14 @@ pub async fn process(
                         return Ok(());
                     }
                     PullRequestState::MERGED { .. } => {
+                        let body = context.message(
+                            room.room_id(),
+                            "nixpkgs_merged",
+                            &[pr_number.into()],
+                        )?;
                         if let Err(error) = room
                             .send_queue()
-                            .send(
-                                RoomMessageEventContent::text_plain(format!(
-                                    "PR #{pr_number} is now merged!"
-                                ))
-                                .into(),
-                            )
+                            .send(RoomMessageEventContent::text_plain(body).into())
                             .await
                         {
                             tracing::warn!(
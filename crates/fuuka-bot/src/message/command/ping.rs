@@ -15,19 +15,18 @@ pub async fn process(
     room: &Room,
     context: &Ctx<Context>,
 ) -> anyhow::Result<()> {
-    let _ = context;
-
     let MilliSecondsSinceUnixEpoch(now) = MilliSecondsSinceUnixEpoch::now();
     let MilliSecondsSinceUnixEpoch(event_ts) = ev.origin_server_ts;
     let now = Duration::milliseconds(now.into());
     let event_ts = Duration::milliseconds(event_ts.into());
     let delta = now - event_ts;
     let delta_ms = delta.whole_milliseconds();
-    let body = if delta_ms >= 2000 {
-        format!("Pong after {delta:.3}")
+    let delta = if delta_ms >= 2000 {
+        format!("{delta:.3}")
     } else {
-        format!("Pong after {delta_ms}ms")
+        format!("{delta_ms}ms")
     };
+    let body = context.message(room.room_id(), "ping", &[delta.into()])?;
 
     room.send(RoomMessageEventContent::text_plain(body).make_reply_to(
         ev,
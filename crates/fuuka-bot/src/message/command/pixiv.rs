@@ -2,10 +2,10 @@ use anyhow::Context as _;
 
 use crate::{
     Context,
-    config::FeaturesConfig,
-    message::pixiv::{PixivCommand, RankingMode},
+    config::{FeaturesConfig, PixivAIFilter},
+    format::locale::Language,
+    message::pixiv::{PageRange, PixivCommand, PixivSubcommand, RankingArgs, RankingMode},
 };
-use futures_util::{StreamExt, pin_mut};
 use matrix_sdk::{
     Room,
     event_handler::Ctx,
@@ -13,34 +13,96 @@ use matrix_sdk::{
         AddMentions, ForwardThread, OriginalRoomMessageEvent, RoomMessageEventContent,
     },
 };
-use pixiv_ajax_api::{PixivClient, ranking::RankingContent};
+use pixiv_ajax_api::PixivClient;
 
 #[tracing::instrument(name = "pixiv", skip_all)]
 pub async fn process(
     ev: &OriginalRoomMessageEvent,
     room: &Room,
     context: &Ctx<Context>,
+    subcommand: Option<PixivSubcommand>,
     command: PixivCommand,
+    pages: Option<PageRange>,
+    ranking_args: RankingArgs,
 ) -> anyhow::Result<()> {
     let Ctx(Context {
         pixiv,
         http,
         features,
+        templates,
         ..
     }) = context;
 
+    let language = context.language(room.room_id());
     let Some((pixiv, context)) = pixiv else {
         return Ok(());
     };
 
+    let room_id = room.room_id();
+    let send_r18 = context.r18 && features.room_pixiv_r18_enabled(room_id);
+    let ai_filter = features.room_pixiv_ai_filter(room_id);
+
+    if let Some(subcommand) = subcommand {
+        let content = match subcommand {
+            PixivSubcommand::Novel { novel_id } => {
+                match format_novel(templates, language, pixiv, novel_id, send_r18)
+                    .await
+                    .context(format!("Failed to query Pixiv novel {novel_id}"))?
+                {
+                    Some(content) => content,
+                    None => {
+                        tracing::debug!(
+                            "Not sending response because the requested novel is marked R-18."
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            PixivSubcommand::User { user_id } => {
+                format_user(templates, language, pixiv, user_id, send_r18)
+                    .await
+                    .context(format!("Failed to query Pixiv user {user_id}"))?
+            }
+            PixivSubcommand::Search { tags, top } => {
+                format_search(templates, language, pixiv, tags, top, send_r18, ai_filter)
+                    .await
+                    .context("Failed to search Pixiv")?
+            }
+        }
+        .make_reply_to(ev, ForwardThread::No, AddMentions::Yes);
+
+        room.send(content).await?;
+
+        return Ok(());
+    }
+
     let content = match command {
-        PixivCommand::Ranking(ranking) => format_ranking(pixiv, ranking)
+        PixivCommand::Ranking(ranking) => {
+            match format_ranking(
+                templates,
+                language,
+                pixiv,
+                ranking,
+                ranking_args,
+                send_r18,
+                ai_filter,
+            )
             .await
-            .context("Failed to query Pixiv ranking")?,
+            .context("Failed to query Pixiv ranking")?
+            {
+                Some(content) => content,
+                None => {
+                    tracing::debug!("Not sending response because the requested ranking is R-18.");
+                    return Ok(());
+                }
+            }
+        }
         PixivCommand::Illust(illust_id) => {
-            send_illust(ev, room, pixiv, http, context, features, illust_id)
-                .await
-                .context(format!("Failed to query Pixiv illust {illust_id}"))?;
+            send_illust(
+                ev, room, pixiv, http, templates, language, context, features, illust_id, pages,
+            )
+            .await
+            .context(format!("Failed to query Pixiv illust {illust_id}"))?;
 
             return Ok(());
         }
@@ -52,63 +114,115 @@ pub async fn process(
     Ok(())
 }
 
-#[tracing::instrument(name = "ranking", skip_all, fields(fuuka_bot.pixiv.ranking = "daily"), err)]
+#[tracing::instrument(name = "ranking", skip_all, fields(fuuka_bot.pixiv.ranking = ?ranking), err)]
 async fn format_ranking(
+    env: &minijinja::Environment,
+    language: Language,
     pixiv: &PixivClient,
     ranking: RankingMode,
-) -> anyhow::Result<RoomMessageEventContent> {
-    let resp = pixiv
-        .ranking_stream(ranking.into(), RankingContent::Illust, None)
-        .await
-        .take(5);
-    let mut body = String::from("Pixiv Ranking: (Illust/Daily)");
-    let mut html_body = String::from("<b>Pixiv Ranking: (Illust/Daily)</b>");
-    let mut idx = 1;
-    pin_mut!(resp);
-    while let Some(illust) = resp.next().await {
-        let illust = illust?;
-        let tag_str = illust
-            .tags
-            .iter()
-            .map(|str| format!("#{str}"))
-            .collect::<Vec<String>>()
-            .join(" ");
-        let tag_html_str = illust
-            .tags
-            .iter()
-            .map(|str| format!("<font color='#3771bb'>#{str}</font>"))
-            .collect::<Vec<String>>()
-            .join(" ");
-        let this_line = format!(
-            "\n#{idx}: {title} https://www.pixiv.net/artworks/{illust_id} | {tag_str}",
-            idx = idx,
-            title = illust.title,
-            illust_id = illust.illust_id
-        );
-        let this_line_html = format!(
-            "<br/>#{idx}: <a href='https://www.pixiv.net/artworks/{illust_id}'>{title}</a> | {tag_html_str}",
-            idx = idx,
-            title = illust.title,
-            illust_id = illust.illust_id
-        );
-        body.push_str(&this_line);
-        html_body.push_str(&this_line_html);
-        idx += 1;
+    args: RankingArgs,
+    send_r18: bool,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    if ranking.is_r18() && !send_r18 {
+        return Ok(None);
     }
-    Ok(RoomMessageEventContent::text_html(body, html_body))
+
+    let RankingArgs {
+        content,
+        date,
+        offset,
+        count,
+    } = args;
+    let date = date.as_ref().map(|date| date.as_str());
+
+    let items = crate::services::pixiv::ranking::request(
+        pixiv, ranking, content, date, offset, count, ai_filter,
+    )
+    .await?;
+    let (body, html_body) = crate::services::pixiv::ranking::format(
+        env, language, &items, ranking, content, date, ai_filter,
+    )?;
+
+    Ok(Some(RoomMessageEventContent::text_html(body, html_body)))
+}
+
+#[tracing::instrument(name = "user", skip_all, fields(fuuka_bot.pixiv.user_id = %user_id), err)]
+async fn format_user(
+    env: &minijinja::Environment,
+    language: Language,
+    pixiv: &PixivClient,
+    user_id: u64,
+    send_r18: bool,
+) -> anyhow::Result<RoomMessageEventContent> {
+    let user = crate::services::pixiv::user::request(pixiv, user_id).await?;
+
+    crate::services::pixiv::user::format(env, language, user, send_r18)
+}
+
+#[tracing::instrument(name = "novel", skip_all, fields(fuuka_bot.pixiv.novel_id = %novel_id), err)]
+async fn format_novel(
+    env: &minijinja::Environment,
+    language: Language,
+    pixiv: &PixivClient,
+    novel_id: u64,
+    send_r18: bool,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    let novel = crate::services::pixiv::novel::request(pixiv, language, novel_id).await?;
+
+    crate::services::pixiv::novel::format(env, language, novel, send_r18)
+}
+
+#[tracing::instrument(name = "search", skip_all, err)]
+async fn format_search(
+    env: &minijinja::Environment,
+    language: Language,
+    pixiv: &PixivClient,
+    tags: Vec<String>,
+    top: usize,
+    send_r18: bool,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<RoomMessageEventContent> {
+    let search =
+        crate::services::pixiv::search::request(pixiv, tags.join(" "), top, send_r18, ai_filter)
+            .await?;
+
+    crate::services::pixiv::search::format(env, language, search, ai_filter)
 }
 
 #[tracing::instrument(name = "illust", skip_all, fields(fuuka_bot.pixiv.illust_id = %illust_id), err)]
+#[allow(clippy::too_many_arguments)]
 async fn send_illust(
     ev: &OriginalRoomMessageEvent,
     room: &Room,
     pixiv: &pixiv_ajax_api::PixivClient,
     http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
     context: &crate::services::pixiv::Context,
     features: &FeaturesConfig,
     illust_id: i32,
+    pages: Option<PageRange>,
 ) -> anyhow::Result<()> {
     let room_id = room.room_id();
     let send_r18 = context.r18 && features.room_pixiv_r18_enabled(room_id);
-    crate::services::pixiv::illust::send(ev, room, pixiv, http, context, illust_id, send_r18).await
+    let r18_preview = features.room_pixiv_r18_preview_enabled(room_id);
+    let ai_filter = features.room_pixiv_ai_filter(room_id);
+    let image_size = features.room_pixiv_image_size(room_id);
+    crate::services::pixiv::illust::send(
+        ev,
+        room,
+        pixiv,
+        http,
+        env,
+        language,
+        context,
+        illust_id,
+        send_r18,
+        r18_preview,
+        ai_filter,
+        image_size,
+        pages.map(Into::into),
+    )
+    .await
 }
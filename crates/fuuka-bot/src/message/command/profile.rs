@@ -52,8 +52,6 @@ pub mod avatar {
             room: &Room,
             context: &Ctx<Context>,
         ) -> anyhow::Result<()> {
-            let _ = context;
-
             use crate::RoomExt as _;
             use crate::RoomMemberExt as _;
 
@@ -69,13 +67,17 @@ pub mod avatar {
                     let info = get_image_info(avatar_url, &client).await?;
                     RoomMessageEventContent::new(MessageType::Image(
                         ImageMessageEventContent::plain(
-                            format!("[Avatar of {name}]"),
+                            context.message(room.room_id(), "avatar_of", &[name.into()])?,
                             avatar_url.into(),
                         )
                         .info(Some(Box::new(info))),
                     ))
                 }
-                None => RoomMessageEventContent::text_plain("The user has no avatar."),
+                None => RoomMessageEventContent::text_plain(context.message(
+                    room.room_id(),
+                    "no_avatar",
+                    &[],
+                )?),
             };
             let content = content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes);
             room.send(content).await?;
@@ -131,6 +133,7 @@ pub mod avatar {
             //let homeserver = { context.config.borrow().matrix.homeserver.clone() };
             let homeserver = room.client().homeserver();
 
+            let messages = context.messages(room.room_id())?;
             let mut body = String::new();
             let current_avatar = member
                 .avatar_url()
@@ -142,10 +145,10 @@ pub mod avatar {
                     }
                 })
                 .transpose()?
-                .map(|result| result.to_string())
-                .unwrap_or("(None)".to_string());
-            let result = format!("Current Avatar: {current_avatar}\n");
+                .map(|result| result.to_string());
+            let result = messages.message("avatar_current", &[current_avatar.into()])?;
             body.push_str(&result);
+            body.push('\n');
             let mut count: i32 = 0;
 
             {
@@ -184,14 +187,22 @@ pub mod avatar {
                                     } else {
                                         avatar_url.http_url(&homeserver)?
                                     };
-                                    let result = format!(
-                                        "{count}: Changed to {avatar_link} ({timestamp})\n"
-                                    );
+                                    let result = messages.message(
+                                        "avatar_changed",
+                                        &[
+                                            count.into(),
+                                            avatar_link.to_string().into(),
+                                            timestamp.into(),
+                                        ],
+                                    )?;
                                     body.push_str(&result);
+                                    body.push('\n');
                                 }
                                 None => {
-                                    let result = format!("{count}: Removed avatar.\n");
+                                    let result =
+                                        messages.message("avatar_removed", &[count.into()])?;
                                     body.push_str(&result);
+                                    body.push('\n');
                                 }
                             }
                         }
@@ -207,14 +218,12 @@ pub mod avatar {
                                         uri.http_url(&homeserver)
                                     }
                                 })
-                                .transpose()?;
-                            let result = format!(
-                                "{count}: Joined with avatar {}\n",
-                                avatar_link
-                                    .map(|link| link.to_string())
-                                    .unwrap_or("(No avatar)".to_string())
-                            );
+                                .transpose()?
+                                .map(|link| link.to_string());
+                            let result = messages
+                                .message("avatar_joined", &[count.into(), avatar_link.into()])?;
                             body.push_str(&result);
+                            body.push('\n');
                         }
                         _ => {}
                     };
@@ -281,7 +290,6 @@ pub mod name {
             room: &Room,
             context: &Ctx<Context>,
         ) -> anyhow::Result<()> {
-            let _ = context;
             let _ = ev;
             use crate::RoomExt as _;
             use futures_util::stream::StreamExt as _;
@@ -293,10 +301,11 @@ pub mod name {
                 return Ok(());
             };
 
+            let messages = context.messages(room.room_id())?;
             let mut body = String::new();
-            let current_name = member.display_name().unwrap_or("(None)");
-            let result = format!("Current Name: {current_name}\n");
+            let result = messages.message("name_current", &[member.display_name().into()])?;
             body.push_str(&result);
+            body.push('\n');
             let mut count: i32 = 0;
 
             {
@@ -330,24 +339,29 @@ pub mod name {
                                     let timestamp =
                                         OffsetDateTime::from_unix_timestamp_nanos(nanos)?
                                             .format(&Rfc3339)?;
-                                    let result = format!(
-                                        "{count}: Changed to {displayname} ({timestamp})\n"
-                                    );
+                                    let result = messages.message(
+                                        "name_changed",
+                                        &[count.into(), displayname.into(), timestamp.into()],
+                                    )?;
                                     body.push_str(&result);
+                                    body.push('\n');
                                 }
                                 None => {
-                                    let result = format!("{count}: Removed display name.\n");
+                                    let result =
+                                        messages.message("name_removed", &[count.into()])?;
                                     body.push_str(&result);
+                                    body.push('\n');
                                 }
                             }
                         }
                         MembershipChange::Joined => {
                             count -= 1;
-                            let result = format!(
-                                "{count}: Joined with display name {}\n",
-                                event.content.displayname.unwrap_or("(No name)".to_string())
-                            );
+                            let result = messages.message(
+                                "name_joined",
+                                &[count.into(), event.content.displayname.into()],
+                            )?;
                             body.push_str(&result);
+                            body.push('\n');
                         }
                         _ => {}
                     };
@@ -24,32 +24,43 @@ pub async fn process(
     }
 
     if !room.is_direct().await? {
-        room.send(
-            RoomMessageEventContent::text_plain("This command is only avaliable in a DM!")
-                .make_reply_to(ev, ForwardThread::No, AddMentions::Yes),
-        )
+        let body = context.message(room.room_id(), "dm_only", &[])?;
+        room.send(RoomMessageEventContent::text_plain(body).make_reply_to(
+            ev,
+            ForwardThread::No,
+            AddMentions::Yes,
+        ))
         .await?;
         return Ok(());
     }
 
+    use crate::format::matrix::rooms::{Context, Room};
+
+    let templates = &context.templates;
     let client = room.client();
-    let joined = client.joined_rooms();
-    let mut body = "Joined rooms: \n".to_string();
-    let mut html_body = "Joined rooms: <br>".to_string();
-    for room in joined {
-        let name = room.display_name().await?;
-        body.push_str(&format!(
-            "- {name} ({room_id}){dm}\n",
-            room_id = room.room_id(),
-            dm = if room.is_direct().await? { " (DM)" } else { "" }
-        ));
-        html_body.push_str(&format!(
-            "- {name} ({room_id}){dm}<br>",
-            room_id = room.room_id(),
-            dm = if room.is_direct().await? { " (DM)" } else { "" }
-        ));
+    let mut joined = Vec::new();
+    for room in client.joined_rooms() {
+        let name = room.display_name().await?.to_string();
+        let direct = room.is_direct().await?;
+        joined.push((room.room_id().to_owned(), name, direct));
     }
 
+    let rooms: Vec<_> = joined
+        .iter()
+        .map(|(id, name, direct)| Room {
+            id: id.as_str(),
+            name,
+            direct: *direct,
+        })
+        .collect();
+    let context = Context {
+        language: context.language(room.room_id()),
+        rooms: &rooms,
+    };
+
+    let body = crate::format::matrix::rooms::text::format(templates, &context)?;
+    let html_body = crate::format::matrix::rooms::html::format(templates, &context)?;
+
     room.send(
         RoomMessageEventContent::text_html(body, html_body).make_reply_to(
             ev,
@@ -13,7 +13,9 @@ pub async fn process(
     context: &Ctx<Context>,
     server_name: Option<OwnedServerName>,
 ) -> anyhow::Result<()> {
-    let Ctx(Context { http, .. }) = context;
+    let Ctx(Context {
+        http, templates, ..
+    }) = context;
     let server_name = server_name.unwrap_or_else(|| room.own_user_id().server_name().to_owned());
 
     let federation_server =
@@ -32,13 +34,20 @@ pub async fn process(
         .map(|server| (server.name, server.version))
         .unwrap_or_default();
 
+    let context = crate::format::matrix::server::Context {
+        server_name: server_name.as_str(),
+        name: name.as_deref(),
+        version: version.as_deref(),
+    };
+    let body = crate::format::matrix::server::text::format(templates, &context)?;
+    let html_body = crate::format::matrix::server::html::format(templates, &context)?;
+
     room.send(
-        RoomMessageEventContent::text_plain(format!(
-            "{server_name}: {name} {version}",
-            name = name.unwrap_or("(Unknown)".to_string()),
-            version = version.unwrap_or("(Unknown)".to_string())
-        ))
-        .make_reply_to(ev, ForwardThread::No, AddMentions::Yes),
+        RoomMessageEventContent::text_html(body, html_body).make_reply_to(
+            ev,
+            ForwardThread::No,
+            AddMentions::Yes,
+        ),
     )
     .await?;
 
@@ -36,7 +36,8 @@ pub async fn process(
         .await
         .context("Failed to unignore user")?;
 
-    room.send(RoomMessageEventContent::text_plain("Done.").make_reply_to(
+    let body = context.message(room.room_id(), "done", &[])?;
+    room.send(RoomMessageEventContent::text_plain(body).make_reply_to(
         ev,
         ForwardThread::No,
         AddMentions::Yes,
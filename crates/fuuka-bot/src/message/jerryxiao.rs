@@ -1,5 +1,6 @@
 //! Implments various Jerry Xiao like functions.
 
+use crate::format::locale::Language;
 use crate::{Context, RoomMemberExt};
 use matrix_sdk::Room;
 use matrix_sdk::event_handler::Ctx;
@@ -127,8 +128,13 @@ pub(super) async fn process(
                     return Ok(());
                 };
 
-                if let Some(content) =
-                    crate::message::jerryxiao::jerryxiao(&from_member, &to_member, &text.0).await?
+                if let Some(content) = crate::message::jerryxiao::jerryxiao(
+                    &context.templates,
+                    &from_member,
+                    &to_member,
+                    &text.0,
+                )
+                .await?
                 {
                     room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
                         .await?;
@@ -149,6 +155,8 @@ pub(super) async fn process(
                 };
 
                 if let Some(content) = crate::message::jerryxiao::jerryxiao_formatted(
+                    &context.templates,
+                    context.language(room.room_id()),
                     &from_member,
                     &to_member,
                     &text.0,
@@ -164,7 +172,14 @@ pub(super) async fn process(
                     return Ok(());
                 };
 
-                let content = crate::message::jerryxiao::fortune(&member, &text.0, prob).await?;
+                let content = crate::message::jerryxiao::fortune(
+                    &context.templates,
+                    context.language(room.room_id()),
+                    &member,
+                    &text.0,
+                    prob,
+                )
+                .await?;
 
                 room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
                     .await?;
@@ -179,7 +194,7 @@ pub(super) async fn process(
 /// Constructs the [RoomMessageEventContent] result of Jerry Xiao from the given room, two senders and text.
 #[tracing::instrument(
     name = "normal",
-    skip(from_member, to_member),
+    skip(env, from_member, to_member),
     fields(
         fuuka_bot.jerryxiao.from_sender = %from_member.user_id(),
         fuuka_bot.jerryxiao.to_sender = %to_member.user_id(),
@@ -187,95 +202,80 @@ pub(super) async fn process(
     err
 )]
 async fn jerryxiao(
+    env: &minijinja::Environment<'_>,
     from_member: &RoomMember,
     to_member: &RoomMember,
     text: &str,
 ) -> anyhow::Result<Option<RoomMessageEventContent>> {
-    let from_pill = from_member.make_pill();
-    let to_pill = to_member.make_pill();
+    let mut splited = text.split_whitespace();
+    let Some(arg0) = splited.next() else {
+        return Ok(None);
+    };
+    // All bytes >= 0x80 are for non ASCII char encoding in UTF-8
+    if !arg0.as_bytes().iter().all(|b| *b >= 0x80) {
+        return Ok(None);
+    }
+
+    let (action, suffix) = if ["把", "拿", "被", "将", "令", "使", "让", "给", "替"]
+        .into_iter()
+        .any(|p| arg0.starts_with(p))
     {
-        let mut splited = text.split_whitespace();
-        if let Some(arg0) = splited.next() {
-            // All bytes >= 0x80 are for non ASCII char encoding in UTF-8
-            if !arg0.as_bytes().iter().all(|b| *b >= 0x80) {
-                return Ok(None);
-            }
-            if ["把", "拿", "被", "将", "令", "使", "让", "给", "替"]
-                .into_iter()
-                .any(|p| arg0.starts_with(p))
-            {
-                let arg1 = splited.next().unwrap_or_default();
-                let arg1 = arg1.strip_suffix('了').unwrap_or(arg1);
-                let arg2 = splited.next().unwrap_or_default();
-                let arg2 = arg2.strip_suffix('了').unwrap_or(arg2);
-                Ok(Some(
-                    RoomMessageEventContent::text_html(
-                        format!(
-                            "@{from} {arg0} @{to} {arg1}了{arg2}",
-                            from = from_member.name(),
-                            to = to_member.name(),
-                        ),
-                        format!("{from_pill} {arg0} {to_pill} {arg1}了{arg2}",),
-                    )
-                    .add_mentions(Mentions::with_user_ids([
-                        from_member.user_id().to_owned(),
-                        to_member.user_id().to_owned(),
-                    ])),
-                ))
-            } else {
-                let arg1 = splited.next();
-                let arg1 = arg1
-                    .map(|arg1| " 的".to_owned() + arg1.strip_prefix('了').unwrap_or(arg1))
-                    .unwrap_or_default();
-                let chars: Vec<char> = arg0.chars().collect();
-                if (chars.len() == 2 && chars[0] == chars[1])
-                    || (chars.len() == 3 && chars[1] == '了' && chars[0] == chars[2])
-                {
-                    Ok(Some(
-                        RoomMessageEventContent::text_html(
-                            format!(
-                                "@{} {}了{} @{}{arg1}",
-                                from_member.name(),
-                                chars[0],
-                                chars[0],
-                                to_member.name(),
-                            ),
-                            format!("{} {}了{} {}{arg1}", from_pill, chars[0], chars[0], to_pill),
-                        )
-                        .add_mentions(Mentions::with_user_ids([
-                            from_member.user_id().to_owned(),
-                            to_member.user_id().to_owned(),
-                        ])),
-                    ))
-                } else {
-                    let arg0 = arg0.strip_suffix('了').unwrap_or(arg0);
-                    Ok(Some(
-                        RoomMessageEventContent::text_html(
-                            format!(
-                                "@{} {arg0}了 @{}{arg1}",
-                                from_member.name(),
-                                to_member.name(),
-                            ),
-                            format!("{from_pill} {arg0}了 {to_pill}{arg1}"),
-                        )
-                        .add_mentions(Mentions::with_user_ids([
-                            from_member.user_id().to_owned(),
-                            to_member.user_id().to_owned(),
-                        ])),
-                    ))
-                }
-            }
+        let arg1 = splited.next().unwrap_or_default();
+        let arg1 = arg1.strip_suffix('了').unwrap_or(arg1);
+        let arg2 = splited.next().unwrap_or_default();
+        let arg2 = arg2.strip_suffix('了').unwrap_or(arg2);
+        (arg0.to_string(), format!(" {arg1}了{arg2}"))
+    } else {
+        let arg1 = splited.next();
+        let arg1 = arg1
+            .map(|arg1| " 的".to_owned() + arg1.strip_prefix('了').unwrap_or(arg1))
+            .unwrap_or_default();
+        let chars: Vec<char> = arg0.chars().collect();
+        if (chars.len() == 2 && chars[0] == chars[1])
+            || (chars.len() == 3 && chars[1] == '了' && chars[0] == chars[2])
+        {
+            (format!("{}了{}", chars[0], chars[0]), arg1)
         } else {
-            Ok(None)
+            let arg0 = arg0.strip_suffix('了').unwrap_or(arg0);
+            (format!("{arg0}了"), arg1)
         }
-    }
+    };
+
+    use crate::format::jerryxiao::{Member, action::Context};
+
+    let from_uri = from_member.user_id().matrix_to_uri().to_string();
+    let to_uri = to_member.user_id().matrix_to_uri().to_string();
+    let context = Context {
+        from: Member {
+            name: from_member.name(),
+            uri: &from_uri,
+        },
+        to: Member {
+            name: to_member.name(),
+            uri: &to_uri,
+        },
+        action: &action,
+        suffix: &suffix,
+    };
+
+    let body = crate::format::jerryxiao::action::text::format(env, &context)?;
+    let html_body = crate::format::jerryxiao::action::html::format(env, &context)?;
+
+    Ok(Some(
+        RoomMessageEventContent::text_html(body, html_body).add_mentions(Mentions::with_user_ids(
+            [
+                from_member.user_id().to_owned(),
+                to_member.user_id().to_owned(),
+            ],
+        )),
+    ))
 }
 
 /// Constructs the [RoomMessageEventContent] result of Jerry Xiao from the given room,
 /// two senders and formatting text.
 #[tracing::instrument(
     name = "formatted",
-    skip(from_member, to_member),
+    skip(env, from_member, to_member),
     fields(
         fuuka_bot.jerryxiao.from_sender = %from_member.user_id(),
         fuuka_bot.jerryxiao.to_sender = %to_member.user_id(),
@@ -283,42 +283,96 @@ async fn jerryxiao(
     err
 )]
 async fn jerryxiao_formatted(
+    env: &minijinja::Environment<'_>,
+    language: Language,
     from_member: &RoomMember,
     to_member: &RoomMember,
     text: &str,
 ) -> anyhow::Result<Option<RoomMessageEventContent>> {
-    if text.contains("{from}") && text.contains("{to}") {
-        let text = text.trim();
-        let from_text = format!("@{}", from_member.name_or_id());
-        let to_text = format!("@{}", to_member.name_or_id());
-        let from_html = from_member.make_pill();
-        let to_html = to_member.make_pill();
-        Ok(Some(
-            RoomMessageEventContent::text_html(
-                formatx::formatx!(text, from = from_text, to = to_text)?,
-                formatx::formatx!(text, from = from_html, to = to_html)?,
-            )
-            .add_mentions(Mentions::with_user_ids([
+    if !(text.contains("{from}") && text.contains("{to}")) {
+        return Ok(Some(RoomMessageEventContent::text_plain(
+            crate::format::locale::message(env, language, "jerryxiao_no_slots", &[])?,
+        )));
+    }
+
+    use crate::format::jerryxiao::{Member, formatted::Context};
+
+    let segments = segments(text.trim())?;
+    let from_uri = from_member.user_id().matrix_to_uri().to_string();
+    let to_uri = to_member.user_id().matrix_to_uri().to_string();
+    let context = Context {
+        from: Member {
+            name: from_member.name_or_id(),
+            uri: &from_uri,
+        },
+        to: Member {
+            name: to_member.name_or_id(),
+            uri: &to_uri,
+        },
+        segments: &segments,
+    };
+
+    let body = crate::format::jerryxiao::formatted::text::format(env, &context)?;
+    let html_body = crate::format::jerryxiao::formatted::html::format(env, &context)?;
+
+    Ok(Some(
+        RoomMessageEventContent::text_html(body, html_body).add_mentions(Mentions::with_user_ids(
+            [
                 from_member.user_id().to_owned(),
                 to_member.user_id().to_owned(),
-            ])),
-        ))
-    } else {
-        Ok(Some(RoomMessageEventContent::text_plain(
-            "No format slot {from} {to} found!",
-        )))
+            ],
+        )),
+    ))
+}
+
+/// Splits the text into plain text and the `{from}` and `{to}` slots.
+///
+/// Like a format string, `{{` and `}}` are literal braces and other slots are rejected.
+fn segments(text: &str) -> anyhow::Result<Vec<crate::format::jerryxiao::formatted::Segment<'_>>> {
+    use crate::format::jerryxiao::formatted::Segment;
+
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(['{', '}']) {
+        if start > 0 {
+            segments.push(Segment::Text {
+                text: &rest[..start],
+            });
+        }
+        rest = &rest[start..];
+
+        let (segment, len) = if rest.starts_with("{{") || rest.starts_with("}}") {
+            (Segment::Text { text: &rest[..1] }, 2)
+        } else if rest.starts_with("{from}") {
+            (Segment::From, "{from}".len())
+        } else if rest.starts_with("{to}") {
+            (Segment::To, "{to}".len())
+        } else {
+            anyhow::bail!(
+                "Only {{from}} and {{to}} are format slots, use {{{{ and }}}} for literal braces!"
+            );
+        };
+        segments.push(segment);
+        rest = &rest[len..];
     }
+    if !rest.is_empty() {
+        segments.push(Segment::Text { text: rest });
+    }
+
+    Ok(segments)
 }
 
 /// Constructs the [RoomMessageEventContent] result of randomdraw from the given room, sender and text.
 #[tracing::instrument(
-    skip(member),
+    skip(env, member),
     fields(
         fuuka_bot.fortune.user_id = %member.user_id(),
     ),
     err
 )]
 async fn fortune(
+    env: &minijinja::Environment<'_>,
+    language: Language,
     member: &RoomMember,
     query: &str,
     prob: bool,
@@ -348,7 +402,6 @@ async fn fortune(
     let mut rng = fastrand::Rng::with_seed(seed);
     let draw_result = rng.u32(0..=10000) as f32 / 10000.0;
     let result_type = rng.bool();
-    let user_pill = member.make_pill();
     let result = if prob {
         let result = if result_type {
             draw_result
@@ -357,63 +410,67 @@ async fn fortune(
         };
         format!("{:.2}%", result * 100.0)
     } else {
-        const CHOICE: [&str; 7] = ["大凶", "凶", "小凶", "尚可", "小吉", "吉", "大吉"];
-        const MAXIDX: usize = CHOICE.len() - 1;
-        let mut resultidx = (draw_result * (CHOICE.len() as f32)) as usize;
+        // Levels are named by the `fortune_level` message, from the worst to the best.
+        const LEVELS: usize = 7;
+        const MAXIDX: usize = LEVELS - 1;
+        let mut resultidx = (draw_result * (LEVELS as f32)) as usize;
         resultidx = if resultidx > MAXIDX {
             MAXIDX
         } else {
             resultidx
         };
-        CHOICE[resultidx].to_string()
+        crate::format::locale::message(env, language, "fortune_level", &[resultidx.into()])?
     };
 
-    let content = if query.is_empty() {
-        if prob {
-            let luck_string = if result_type {
-                "行大运"
-            } else {
-                "倒大霉"
-            };
-            RoomMessageEventContent::text_html(
-                format!(
-                    "你好, @{}\n汝今天{}概率是 {}",
-                    member.name(),
-                    luck_string,
-                    result
-                ),
-                format!("你好, {user_pill}<br/>汝今天{luck_string}概率是 {result}"),
-            )
-        } else {
-            RoomMessageEventContent::text_html(
-                format!("你好, @{}\n汝的今日运势: {}", member.name(), result),
-                format!("你好, {user_pill}<br/>汝的今日运势: {result}"),
-            )
-        }
-    } else if prob {
-        let happen_or_not_string = if result_type { "发生" } else { "不发生" };
-        RoomMessageEventContent::text_html(
-            format!(
-                "你好, @{}\n所求事项: {}\n结果: 此事有 {} 的概率{}",
-                member.name(),
-                query,
-                result,
-                happen_or_not_string
-            ),
-            format!(
-                "你好, {user_pill}<br/>所求事项: {query}<br/>结果: 此事有 {result} 的概率{happen_or_not_string}"
-            ),
-        )
-    } else {
-        RoomMessageEventContent::text_html(
-            format!(
-                "你好, @{}\n所求事项: {}\n结果: {}",
-                member.name(),
-                query,
-                result
-            ),
-            format!("你好, {user_pill}<br/>所求事项: {query}<br/>结果: {result}"),
-        )
+    use crate::format::jerryxiao::{Member, fortune::Context};
+
+    let uri = user_id.matrix_to_uri().to_string();
+    let context = Context {
+        language,
+        member: Member {
+            name: member.name(),
+            uri: &uri,
+        },
+        query: (!query.is_empty()).then_some(query),
+        prob,
+        lucky: result_type,
+        result: &result,
     };
-    Ok(content)
+
+    let body = crate::format::jerryxiao::fortune::text::format(env, &context)?;
+    let html_body = crate::format::jerryxiao::fortune::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::jerryxiao::formatted::Segment;
+    use pretty_assertions::assert_eq;
+
+    fn render(segments: &[Segment<'_>]) -> String {
+        segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text { text } => text,
+                Segment::From => "<from>",
+                Segment::To => "<to>",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn segments_split_slots() {
+        let segments = super::segments("{from} 给 {to} 递了 {{x}} 和 {{to}}").unwrap();
+
+        assert_eq!(render(&segments), "<from> 给 <to> 递了 {x} 和 {to}");
+        assert!(matches!(segments.first(), Some(Segment::From)));
+    }
+
+    #[test]
+    fn segments_reject_unknown_slots() {
+        assert!(super::segments("{from} 给 {to} 递了 {x}").is_err());
+        assert!(super::segments("{from} 给 {to} 递了 }").is_err());
+        assert!(super::segments("{from} 给 {to} 递了 {").is_err());
+    }
 }
@@ -145,10 +145,17 @@ pub enum Args {
     /// Unignore a user.
     Unignore { user_id: OwnedUserId },
     /// Pixiv related commands.
+    #[command(args_conflicts_with_subcommands = true)]
     Pixiv {
+        #[command(subcommand)]
+        subcommand: Option<self::pixiv::PixivSubcommand>,
         /// Either a numeric illust id, or a ranking category.
         #[arg(name = "MODE_OR_ILLUST_ID", default_value = "daily")]
         command: self::pixiv::PixivCommand,
+        /// Pages of the illust to send, like `2` or `2-4`.
+        pages: Option<self::pixiv::PageRange>,
+        #[command(flatten)]
+        ranking: self::pixiv::RankingArgs,
     },
     /// Bot management commands,
     #[command(subcommand)]
@@ -168,7 +175,7 @@ pub enum Args {
     UserId,
     /// (Admin only) Print info regarding joined rooms.
     Rooms,
-    /// Send infomation of a BiliBili video.
+    /// Send infomation of a BiliBili video, user, bangumi or live room.
     #[command(name = "bilibili")]
     BiliBili { id: String },
     /// Ger server infomation.
@@ -192,6 +199,22 @@ pub mod profile {
 }
 
 pub mod pixiv {
+    #[derive(Clone, Debug, clap::Subcommand)]
+    pub enum PixivSubcommand {
+        /// Send infomation of an user and their latest works.
+        User { user_id: u64 },
+        /// Send infomation of a novel.
+        Novel { novel_id: u64 },
+        /// Search the newest artworks with the given tags.
+        Search {
+            #[arg(required = true)]
+            tags: Vec<String>,
+            /// How many results to send.
+            #[arg(long, default_value_t = 5)]
+            top: usize,
+        },
+    }
+
     #[derive(Clone, Copy, Debug)]
     pub enum PixivCommand {
         Ranking(RankingMode),
@@ -252,7 +275,80 @@ pub mod pixiv {
         }
     }
 
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+    /// Options of the ranking.
+    #[derive(Clone, Debug, clap::Args)]
+    pub struct RankingArgs {
+        /// The type of works in the ranking.
+        #[arg(long, value_enum, default_value_t)]
+        pub content: RankingContent,
+        /// The date of the ranking in `YYYYMMDD` format, defaults to the latest one.
+        #[arg(long)]
+        pub date: Option<RankingDate>,
+        /// How many items to skip.
+        #[arg(long, default_value_t = 0)]
+        pub offset: usize,
+        /// How many items to send.
+        #[arg(long, default_value_t = 5)]
+        pub count: usize,
+    }
+
+    /// A date in `YYYYMMDD` format.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RankingDate(String);
+
+    impl RankingDate {
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl std::str::FromStr for RankingDate {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format!("Invalid date: {s}, expected YYYYMMDD"));
+            }
+
+            Ok(Self(s.to_string()))
+        }
+    }
+
+    /// A range of pages, starting from 1.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PageRange {
+        pub start: usize,
+        pub end: usize,
+    }
+
+    impl std::str::FromStr for PageRange {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (start, end) = s.split_once('-').unwrap_or((s, s));
+            let start: usize = start
+                .parse()
+                .map_err(|_| format!("Invalid start page: {start}"))?;
+            let end: usize = end
+                .parse()
+                .map_err(|_| format!("Invalid end page: {end}"))?;
+
+            if start == 0 || start > end {
+                return Err(format!("Invalid page range: {s}"));
+            }
+
+            Ok(Self { start, end })
+        }
+    }
+
+    impl From<PageRange> for std::ops::RangeInclusive<usize> {
+        fn from(value: PageRange) -> Self {
+            value.start..=value.end
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
     pub enum RankingMode {
         #[default]
         Daily,
@@ -266,9 +362,72 @@ pub mod pixiv {
         WeeklyR18,
         MaleR18,
         FemaleR18,
+        #[serde(rename = "r18g")]
         R18G,
     }
 
+    impl RankingMode {
+        /// The name shown in the ranking header.
+        pub fn name(self) -> &'static str {
+            match self {
+                Self::Daily => "Daily",
+                Self::Weekly => "Weekly",
+                Self::Monthly => "Monthly",
+                Self::Rookie => "Rookie",
+                Self::Original => "Original",
+                Self::Male => "Male",
+                Self::Female => "Female",
+                Self::DailyR18 => "Daily R-18",
+                Self::WeeklyR18 => "Weekly R-18",
+                Self::MaleR18 => "Male R-18",
+                Self::FemaleR18 => "Female R-18",
+                Self::R18G => "R-18G",
+            }
+        }
+
+        /// Whether the ranking only contains R-18 or R-18G works.
+        pub fn is_r18(self) -> bool {
+            matches!(
+                self,
+                Self::DailyR18 | Self::WeeklyR18 | Self::MaleR18 | Self::FemaleR18 | Self::R18G
+            )
+        }
+    }
+
+    /// The type of works in a ranking.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum RankingContent {
+        All,
+        #[default]
+        Illust,
+        Ugoira,
+        Manga,
+    }
+
+    impl RankingContent {
+        /// The name shown in the ranking header.
+        pub fn name(self) -> &'static str {
+            match self {
+                Self::All => "All",
+                Self::Illust => "Illust",
+                Self::Ugoira => "Ugoira",
+                Self::Manga => "Manga",
+            }
+        }
+    }
+
+    impl From<RankingContent> for pixiv_ajax_api::ranking::RankingContent {
+        fn from(value: RankingContent) -> Self {
+            match value {
+                RankingContent::All => Self::All,
+                RankingContent::Illust => Self::Illust,
+                RankingContent::Ugoira => Self::Ugoira,
+                RankingContent::Manga => Self::Manga,
+            }
+        }
+    }
+
     impl From<RankingMode> for pixiv_ajax_api::ranking::RankingMode {
         fn from(value: RankingMode) -> Self {
             match value {
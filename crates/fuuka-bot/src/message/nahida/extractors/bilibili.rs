@@ -0,0 +1,39 @@
+//! Extracts BiliBili URLs, including `b23.tv` short links.
+
+use anyhow::Context;
+use matrix_sdk::{
+    Room,
+    ruma::events::room::message::{OriginalRoomMessageEvent, RoomMessageEventContent},
+};
+
+use crate::format::locale::Language;
+use crate::message::nahida::link_type::{BiliBiliLinkType, LinkType};
+
+#[tracing::instrument(name = "bilibili", skip(ev, room, bilibili, client, env), err)]
+pub async fn bilibili(
+    ev: &OriginalRoomMessageEvent,
+    room: &Room,
+    link: BiliBiliLinkType,
+    bilibili: &bilibili_api::BiliBiliClient,
+    client: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    let resource = match link {
+        BiliBiliLinkType::Resource(resource) => resource,
+        BiliBiliLinkType::ShortLink(url) => {
+            let url = crate::services::bilibili::resolve_short_link(client, url)
+                .await
+                .context("Failed to resolve b23.tv short link")?;
+            match url.try_into()? {
+                LinkType::BiliBili(BiliBiliLinkType::Resource(resource)) => resource,
+                LinkType::Generic(url) => {
+                    return super::generic::extract(client, url).await;
+                }
+                _ => return Ok(None),
+            }
+        }
+    };
+
+    crate::services::bilibili::lookup(ev, room, bilibili, client, env, language, resource).await
+}
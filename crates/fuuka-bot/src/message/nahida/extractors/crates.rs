@@ -2,45 +2,46 @@
 
 use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
 
-#[tracing::instrument(name = "crates", skip(client), err)]
+use crate::format::locale::Language;
+
+#[tracing::instrument(name = "crates", skip(client, env), err)]
 pub async fn crates_crate(
     name: String,
     version: Option<String>,
     client: &crates_api::CratesClient,
+    env: &minijinja::Environment,
+    language: Language,
 ) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    use crate::format::crates::info::Context;
+
     let resp = client.crate_info(name).await?;
     let version = version
         .as_ref()
         .unwrap_or(&resp.crate_info.max_stable_version);
 
-    let name = resp.crate_info.name;
-    let desc = resp
-        .crate_info
-        .description
-        .unwrap_or("(No Description)".to_string());
-    let repo = resp
-        .crate_info
-        .repository
-        .map(|s| format!("\nRepository: {s}"))
-        .unwrap_or_default();
+    let name = &resp.crate_info.name;
     let docs = resp
         .crate_info
         .documentation
-        .map(|s| format!("\nDocs: {s}"))
-        .unwrap_or_else(|| format!("\nDocs: https://docs.rs/{name}/{version}"));
+        .clone()
+        .unwrap_or_else(|| format!("https://docs.rs/{name}/{version}"));
     let version_info = resp
         .versions
-        .and_then(|versions| versions.into_iter().find(|i| i.num == *version));
-
-    let msrv_str = version_info
-        .and_then(|info| info.rust_version)
-        .map(|msrv| format!("\nMSRV: {msrv}",))
-        .unwrap_or_default();
-
-    Ok(Some(RoomMessageEventContent::text_html(
-        format!("[Rust/Crate] {name} v{version}: {desc}\n{msrv_str}{docs}{repo}"),
-        format!(
-            "<p><b>[Rust/Crate]</b> {name} v{version}: {desc}</p><p>{msrv_str}<br/>{docs}<br/>{repo}</p>"
-        ),
-    )))
+        .as_ref()
+        .and_then(|versions| versions.iter().find(|i| i.num == *version));
+
+    let context = Context {
+        language,
+        name,
+        version,
+        description: resp.crate_info.description.as_deref(),
+        msrv: version_info.and_then(|info| info.rust_version.as_deref()),
+        docs: &docs,
+        repository: resp.crate_info.repository.as_deref(),
+    };
+
+    let body = crate::format::crates::info::text::format(env, &context)?;
+    let html_body = crate::format::crates::info::html::format(env, &context)?;
+
+    Ok(Some(RoomMessageEventContent::text_html(body, html_body)))
 }
@@ -9,6 +9,8 @@ use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
 use mime::Mime;
 use url::Url;
 
+use crate::format::sanitize::{escape, html_to_text};
+
 #[tracing::instrument(name = "generic", skip_all, err)]
 pub async fn extract(
     client: &reqwest::Client,
@@ -27,32 +29,28 @@ pub async fn extract(
         Some(content_type) => {
             let content_type = Mime::from_str(content_type.to_str()?)?;
             if (content_type.type_(), content_type.subtype()) == (mime::TEXT, mime::HTML) {
-                parse_html_title(&resp.text().await?).map(|ok| {
-                    ok.map(|str| {
-                        RoomMessageEventContent::text_html(
-                            format!("[Generic] Page Title: {str}"),
-                            format!("<b>[Generic]</b> Page Title: {str}"),
-                        )
-                    })
-                })
+                parse_html_title(&resp.text().await?).map(|ok| ok.map(page_title))
             } else {
                 Ok(Some(RoomMessageEventContent::text_html(
                     format!("[Generic] Content Type: {content_type}"),
-                    format!("<b>[Generic]</b> Content Type: {content_type}"),
+                    format!(
+                        "<b>[Generic]</b> Content Type: {}",
+                        escape(content_type.as_ref())
+                    ),
                 )))
             }
         }
-        None => parse_html_title(&resp.text().await?).map(|ok| {
-            ok.map(|str| {
-                RoomMessageEventContent::text_html(
-                    format!("[Generic] Page Title: {str}"),
-                    format!("<b>[Generic]</b> Page Title: {str}"),
-                )
-            })
-        }),
+        None => parse_html_title(&resp.text().await?).map(|ok| ok.map(page_title)),
     }
 }
 
+fn page_title(title: String) -> RoomMessageEventContent {
+    RoomMessageEventContent::text_html(
+        format!("[Generic] Page Title: {title}"),
+        format!("<b>[Generic]</b> Page Title: {}", escape(&title)),
+    )
+}
+
 fn parse_html_title(input: &str) -> anyhow::Result<Option<String>> {
     let dom = tl::parse(input, tl::ParserOptions::default())?;
     let parser = dom.parser();
@@ -63,8 +61,11 @@ fn parse_html_title(input: &str) -> anyhow::Result<Option<String>> {
                 return Ok(None);
             };
 
-            let title = elem.get(parser).map(|node| node.inner_text(parser));
-            Ok(title.as_deref().map(ToString::to_string))
+            // The title may contain character references.
+            let title = elem
+                .get(parser)
+                .map(|node| html_to_text(&node.inner_text(parser)));
+            Ok(title)
         }
         None => Ok(None),
     }
@@ -83,4 +84,29 @@ mod tests {
 
         assert_eq!(res, req);
     }
+
+    #[test]
+    fn parse_html_title_with_references() {
+        let str = r#"<html><head><title>Fish &amp; Chips &lt;3</title></head></html>"#;
+        let res = parse_html_title(str).unwrap();
+        let req = Some("Fish & Chips <3".to_string());
+
+        assert_eq!(res, req);
+    }
+
+    #[test]
+    fn page_title_is_escaped() {
+        use matrix_sdk::ruma::events::room::message::MessageType;
+
+        let content = super::page_title("<img src=x> & co".to_string());
+        let MessageType::Text(text) = content.msgtype else {
+            panic!("Not a text message");
+        };
+
+        assert_eq!(text.body, "[Generic] Page Title: <img src=x> & co");
+        assert_eq!(
+            text.formatted.map(|formatted| formatted.body).as_deref(),
+            Some("<b>[Generic]</b> Page Title: &lt;img src=x&gt; &amp; co")
+        );
+    }
 }
@@ -0,0 +1,40 @@
+//! Extracts GitHub URLs.
+
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+
+use crate::message::nahida::link_type::GitHubLinkType;
+
+#[tracing::instrument(name = "github", skip(octocrab, env), err)]
+pub async fn github(
+    link: GitHubLinkType,
+    octocrab: &octocrab::Octocrab,
+    env: &minijinja::Environment,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    use crate::services::github::preview;
+
+    let content = match link {
+        GitHubLinkType::Repository(repository) => {
+            preview::repository(env, octocrab, &repository).await?
+        }
+        GitHubLinkType::Commit { repository, sha } => {
+            preview::commit(env, octocrab, &repository, &sha).await?
+        }
+        GitHubLinkType::PullRequest { repository, number } => {
+            preview::pull_request(env, octocrab, &repository, number).await?
+        }
+        GitHubLinkType::Issue { repository, number } => {
+            preview::issue(env, octocrab, &repository, number).await?
+        }
+        GitHubLinkType::Release { repository, tag } => {
+            preview::release(env, octocrab, &repository, tag.as_deref()).await?
+        }
+        GitHubLinkType::Blob {
+            repository,
+            reference,
+            path,
+            lines,
+        } => preview::blob(env, octocrab, &repository, &reference, &path, lines).await?,
+    };
+
+    Ok(Some(content))
+}
@@ -1,4 +1,6 @@
 //! URL infomation extractors.
+pub mod bilibili;
 pub mod crates;
 pub mod generic;
+pub mod github;
 pub mod pixiv;
@@ -1,21 +1,68 @@
 //! Extracts Pixiv URLs.
+use crate::format::locale::Language;
 use matrix_sdk::{
     Room,
     ruma::events::room::message::{OriginalRoomMessageEvent, RoomMessageEventContent},
 };
 
 #[tracing::instrument(name = "illust", skip_all, fields(fuuka_bot.pixiv.illust_id = %illust_id), err)]
+#[allow(clippy::too_many_arguments)]
 pub async fn pixiv_illust(
     ev: &OriginalRoomMessageEvent,
     room: &Room,
     pixiv: &pixiv_ajax_api::PixivClient,
     http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
     illust_id: i32,
     context: &crate::services::pixiv::Context,
     send_r18: bool,
+    r18_preview: bool,
+    ai_filter: crate::config::PixivAIFilter,
+    image_size: crate::config::PixivImageSize,
 ) -> anyhow::Result<Option<RoomMessageEventContent>> {
-    crate::services::pixiv::illust::send(ev, room, pixiv, http, context, illust_id, send_r18)
-        .await?;
+    crate::services::pixiv::illust::send(
+        ev,
+        room,
+        pixiv,
+        http,
+        env,
+        language,
+        context,
+        illust_id,
+        send_r18,
+        r18_preview,
+        ai_filter,
+        image_size,
+        None,
+    )
+    .await?;
 
     return Ok(None);
 }
+
+#[tracing::instrument(name = "user", skip_all, fields(fuuka_bot.pixiv.user_id = %user_id), err)]
+pub async fn pixiv_user(
+    env: &minijinja::Environment,
+    language: Language,
+    pixiv: &pixiv_ajax_api::PixivClient,
+    user_id: u64,
+    send_r18: bool,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    let user = crate::services::pixiv::user::request(pixiv, user_id).await?;
+
+    crate::services::pixiv::user::format(env, language, user, send_r18).map(Some)
+}
+
+#[tracing::instrument(name = "novel", skip_all, fields(fuuka_bot.pixiv.novel_id = %novel_id), err)]
+pub async fn pixiv_novel(
+    env: &minijinja::Environment,
+    language: Language,
+    pixiv: &pixiv_ajax_api::PixivClient,
+    novel_id: u64,
+    send_r18: bool,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    let novel = crate::services::pixiv::novel::request(pixiv, language, novel_id).await?;
+
+    crate::services::pixiv::novel::format(env, language, novel, send_r18)
+}
@@ -1,10 +1,52 @@
+use std::ops::RangeInclusive;
+
 use anyhow::Context;
 use url::{Host, Url};
 
+use crate::config::RepositoryParts;
+use crate::services::bilibili::resource::Resource;
+
+/// First path segments of GitHub pages that are not users or organizations.
+const GITHUB_RESERVED_OWNERS: &[&str] = &[
+    "about",
+    "account",
+    "apps",
+    "codespaces",
+    "collections",
+    "dashboard",
+    "enterprise",
+    "events",
+    "explore",
+    "features",
+    "issues",
+    "login",
+    "logout",
+    "marketplace",
+    "new",
+    "notifications",
+    "organizations",
+    "orgs",
+    "pricing",
+    "pulls",
+    "search",
+    "security",
+    "sessions",
+    "settings",
+    "signup",
+    "site",
+    "sponsors",
+    "stars",
+    "topics",
+    "trending",
+    "users",
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) enum LinkType {
     Crates(CrateLinkType),
     Pixiv(PixivLinkType),
+    GitHub(GitHubLinkType),
+    BiliBili(BiliBiliLinkType),
     Generic(Url),
     CannotBeABase,
 }
@@ -35,6 +77,37 @@ impl LinkType {
 
         let p01 = paths.next();
 
+        if let Some("novel") = p01 {
+            if paths.next() != Some("show.php") {
+                return Ok(LinkType::Generic(url));
+            }
+
+            let novel_id = url
+                .query_pairs()
+                .find(|(key, _)| key == "id")
+                .map(|(_, id)| id.parse::<u64>())
+                .transpose()
+                .context("Failed to parse Pixiv novel ID")?;
+
+            return match novel_id {
+                Some(novel_id) => Ok(LinkType::Pixiv(PixivLinkType::Novel(novel_id))),
+                None => Ok(LinkType::Generic(url)),
+            };
+        }
+
+        if let Some("users" | "u") = p01 {
+            let user_id = paths
+                .next()
+                .map(|i| i.parse::<u64>())
+                .transpose()
+                .context("Failed to parse Pixiv user ID")?;
+
+            return match user_id {
+                Some(user_id) => Ok(LinkType::Pixiv(PixivLinkType::User(user_id))),
+                None => Ok(LinkType::Generic(url)),
+            };
+        }
+
         if !p01
             .map(|p01| ["artworks", "i"].into_iter().any(|allowed| allowed == p01))
             .unwrap_or_default()
@@ -53,6 +126,80 @@ impl LinkType {
             None => Ok(LinkType::Generic(url)),
         }
     }
+
+    fn parse_github(url: Url) -> LinkType {
+        let Some(paths) = url.path_segments() else {
+            return LinkType::CannotBeABase;
+        };
+        let paths: Vec<&str> = paths.filter(|p| !p.is_empty()).collect();
+
+        let [owner, repo, rest @ ..] = paths.as_slice() else {
+            return LinkType::Generic(url);
+        };
+        if GITHUB_RESERVED_OWNERS.contains(owner) {
+            return LinkType::Generic(url);
+        }
+        let repository = RepositoryParts {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        };
+
+        let link = match rest {
+            [] => GitHubLinkType::Repository(repository),
+            ["commit", sha, ..] => GitHubLinkType::Commit {
+                repository,
+                sha: sha.to_string(),
+            },
+            // Pages like `pull/new/<branch>` and `issues/new` are not numbered.
+            ["pull", number, ..] => match number.parse() {
+                Ok(number) => GitHubLinkType::PullRequest { repository, number },
+                Err(_) => return LinkType::Generic(url),
+            },
+            ["issues", number, ..] => match number.parse() {
+                Ok(number) => GitHubLinkType::Issue { repository, number },
+                Err(_) => return LinkType::Generic(url),
+            },
+            ["releases", "latest"] => GitHubLinkType::Release {
+                repository,
+                tag: None,
+            },
+            ["releases", "tag", tag] => GitHubLinkType::Release {
+                repository,
+                tag: Some(tag.to_string()),
+            },
+            ["blob", reference, path @ ..] if !path.is_empty() => GitHubLinkType::Blob {
+                repository,
+                reference: reference.to_string(),
+                path: path.join("/"),
+                lines: url.fragment().and_then(parse_line_anchor),
+            },
+            _ => return LinkType::Generic(url),
+        };
+
+        LinkType::GitHub(link)
+    }
+
+    fn parse_bilibili(url: Url) -> anyhow::Result<LinkType> {
+        match Resource::from_url(&url)? {
+            Some(resource) => Ok(LinkType::BiliBili(BiliBiliLinkType::Resource(resource))),
+            None => Ok(LinkType::Generic(url)),
+        }
+    }
+}
+
+/// Parses a GitHub line anchor like `L10` or `L10-L20`.
+fn parse_line_anchor(fragment: &str) -> Option<RangeInclusive<usize>> {
+    let (start, end) = match fragment.split_once('-') {
+        Some((start, end)) => (start, Some(end)),
+        None => (fragment, None),
+    };
+    let start: usize = start.strip_prefix('L')?.parse().ok()?;
+    let end: usize = match end {
+        Some(end) => end.strip_prefix('L')?.parse().ok()?,
+        None => start,
+    };
+
+    (start > 0 && start <= end).then_some(start..=end)
 }
 
 impl TryFrom<Url> for LinkType {
@@ -66,6 +213,16 @@ impl TryFrom<Url> for LinkType {
                 Some(Host::Domain("crates.io")) => Ok(Self::parse_crates_io(value)),
                 Some(Host::Domain("www.pixiv.net")) => Self::parse_pixiv(value),
                 Some(Host::Domain("pixiv.net")) => Self::parse_pixiv(value),
+                Some(Host::Domain("github.com")) => Ok(Self::parse_github(value)),
+                Some(Host::Domain("www.github.com")) => Ok(Self::parse_github(value)),
+                Some(Host::Domain("www.bilibili.com")) => Self::parse_bilibili(value),
+                Some(Host::Domain("bilibili.com")) => Self::parse_bilibili(value),
+                Some(Host::Domain("m.bilibili.com")) => Self::parse_bilibili(value),
+                Some(Host::Domain("space.bilibili.com")) => Self::parse_bilibili(value),
+                Some(Host::Domain("live.bilibili.com")) => Self::parse_bilibili(value),
+                Some(Host::Domain("b23.tv")) => {
+                    Ok(LinkType::BiliBili(BiliBiliLinkType::ShortLink(value)))
+                }
                 _ => Ok(LinkType::Generic(value)),
             }
         }
@@ -83,6 +240,44 @@ pub(super) enum CrateLinkType {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) enum PixivLinkType {
     Artwork(i32),
+    User(u64),
+    Novel(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum GitHubLinkType {
+    Repository(RepositoryParts),
+    Commit {
+        repository: RepositoryParts,
+        sha: String,
+    },
+    PullRequest {
+        repository: RepositoryParts,
+        number: i32,
+    },
+    Issue {
+        repository: RepositoryParts,
+        number: u64,
+    },
+    Release {
+        repository: RepositoryParts,
+        /// The release tag, or [None] for the latest release.
+        tag: Option<String>,
+    },
+    Blob {
+        repository: RepositoryParts,
+        reference: String,
+        path: String,
+        lines: Option<RangeInclusive<usize>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum BiliBiliLinkType {
+    /// A video, user, bangumi or live room.
+    Resource(Resource),
+    /// A `b23.tv` short link, which needs to be resolved first.
+    ShortLink(Url),
 }
 
 #[cfg(test)]
@@ -90,7 +285,11 @@ mod tests {
     use pretty_assertions::assert_eq;
     use url::Url;
 
-    use crate::message::nahida::link_type::{CrateLinkType, PixivLinkType};
+    use crate::config::RepositoryParts;
+    use crate::message::nahida::link_type::{
+        BiliBiliLinkType, CrateLinkType, GitHubLinkType, PixivLinkType,
+    };
+    use crate::services::bilibili::{id::VideoId, resource::Resource};
 
     use super::LinkType;
 
@@ -126,4 +325,150 @@ mod tests {
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn parse_pixiv_user_ok() {
+        let url = Url::parse("https://www.pixiv.net/users/11").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::Pixiv(PixivLinkType::User(11));
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_pixiv_novel_ok() {
+        let url = Url::parse("https://www.pixiv.net/novel/show.php?id=20000001").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::Pixiv(PixivLinkType::Novel(20000001));
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_github_repository_ok() {
+        let url = Url::parse("https://github.com/ShadowRZ/fuuka-bot").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::GitHub(GitHubLinkType::Repository(RepositoryParts {
+            owner: "ShadowRZ".to_string(),
+            repo: "fuuka-bot".to_string(),
+        }));
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_github_pull_request_ok() {
+        let url = Url::parse("https://github.com/NixOS/nixpkgs/pull/300000/files").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::GitHub(GitHubLinkType::PullRequest {
+            repository: RepositoryParts {
+                owner: "NixOS".to_string(),
+                repo: "nixpkgs".to_string(),
+            },
+            number: 300000,
+        });
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_github_unnumbered_pages_as_generic() {
+        for url in [
+            "https://github.com/NixOS/nixpkgs/issues/new",
+            "https://github.com/NixOS/nixpkgs/pull/new/branch",
+            "https://github.com/NixOS/nixpkgs/issues?q=is%3Aopen",
+        ] {
+            let url = Url::parse(url).unwrap();
+            let result: LinkType = url.clone().try_into().unwrap();
+
+            assert_eq!(LinkType::Generic(url), result);
+        }
+    }
+
+    #[test]
+    fn parse_github_reserved_pages_as_generic() {
+        for url in [
+            "https://github.com/settings/profile",
+            "https://github.com/orgs/NixOS",
+            "https://github.com/features/actions",
+        ] {
+            let url = Url::parse(url).unwrap();
+            let result: LinkType = url.clone().try_into().unwrap();
+
+            assert_eq!(LinkType::Generic(url), result);
+        }
+    }
+
+    #[test]
+    fn parse_github_release_ok() {
+        let url = Url::parse("https://github.com/ShadowRZ/fuuka-bot/releases/tag/v0.4.7").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::GitHub(GitHubLinkType::Release {
+            repository: RepositoryParts {
+                owner: "ShadowRZ".to_string(),
+                repo: "fuuka-bot".to_string(),
+            },
+            tag: Some("v0.4.7".to_string()),
+        });
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_github_blob_with_lines_ok() {
+        let url = Url::parse(
+            "https://github.com/ShadowRZ/fuuka-bot/blob/master/crates/fuuka-bot/src/lib.rs#L10-L20",
+        )
+        .unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::GitHub(GitHubLinkType::Blob {
+            repository: RepositoryParts {
+                owner: "ShadowRZ".to_string(),
+                repo: "fuuka-bot".to_string(),
+            },
+            reference: "master".to_string(),
+            path: "crates/fuuka-bot/src/lib.rs".to_string(),
+            lines: Some(10..=20),
+        });
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_github_blob_with_single_line_ok() {
+        let url =
+            Url::parse("https://github.com/ShadowRZ/fuuka-bot/blob/master/Cargo.toml#L3").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::GitHub(GitHubLinkType::Blob {
+            repository: RepositoryParts {
+                owner: "ShadowRZ".to_string(),
+                repo: "fuuka-bot".to_string(),
+            },
+            reference: "master".to_string(),
+            path: "Cargo.toml".to_string(),
+            lines: Some(3..=3),
+        });
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_bilibili_video_ok() {
+        let url = Url::parse("https://www.bilibili.com/video/BV1GJ411x7h7/?p=1").unwrap();
+        let result: LinkType = url.try_into().unwrap();
+        let expected = LinkType::BiliBili(BiliBiliLinkType::Resource(Resource::Video(
+            VideoId::from_aid(80433022),
+        )));
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_bilibili_short_link_ok() {
+        let url = Url::parse("https://b23.tv/BV1GJ411x7h7").unwrap();
+        let result: LinkType = url.clone().try_into().unwrap();
+        let expected = LinkType::BiliBili(BiliBiliLinkType::ShortLink(url));
+
+        assert_eq!(expected, result);
+    }
 }
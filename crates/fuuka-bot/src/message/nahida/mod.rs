@@ -9,6 +9,10 @@
 //! ```text
 //! # Outputs infomation for Rust crate syn
 //! @Nahida https://crates.io/crates/syn
+//! # Outputs lines 10 to 20 of a file on GitHub
+//! @Nahida https://github.com/ShadowRZ/fuuka-bot/blob/master/Cargo.toml#L10-L20
+//! # Outputs infomation for a BiliBili video, short links are resolved first
+//! @Nahida https://b23.tv/BV1GJ411x7h7
 //! ```
 
 mod extractors;
@@ -60,24 +64,67 @@ async fn dispatch(
         http: client,
         features,
         crates,
+        bilibili,
+        octocrab,
+        templates,
         ..
     } = context;
+    let language = context.language(room.room_id());
 
     match url.try_into()? {
         LinkType::Crates(CrateLinkType::CrateInfo { name, version }) => {
-            self::extractors::crates::crates_crate(name, version, crates).await
+            self::extractors::crates::crates_crate(name, version, crates, templates, language).await
         }
         LinkType::Pixiv(PixivLinkType::Artwork(artwork_id)) => match &context.pixiv {
             Some((pixiv, context)) => {
                 let send_r18 = context.r18 && features.room_pixiv_r18_enabled(room.room_id());
+                let r18_preview = features.room_pixiv_r18_preview_enabled(room.room_id());
+                let ai_filter = features.room_pixiv_ai_filter(room.room_id());
+                let image_size = features.room_pixiv_image_size(room.room_id());
                 self::extractors::pixiv::pixiv_illust(
-                    ev, room, pixiv, client, artwork_id, context, send_r18,
+                    ev,
+                    room,
+                    pixiv,
+                    client,
+                    templates,
+                    language,
+                    artwork_id,
+                    context,
+                    send_r18,
+                    r18_preview,
+                    ai_filter,
+                    image_size,
                 )
                 .instrument(tracing::info_span!("pixiv")) // TODO
                 .await
             }
             None => Ok(None),
         },
+        LinkType::Pixiv(PixivLinkType::User(user_id)) => match &context.pixiv {
+            Some((pixiv, context)) => {
+                let send_r18 = context.r18 && features.room_pixiv_r18_enabled(room.room_id());
+                self::extractors::pixiv::pixiv_user(templates, language, pixiv, user_id, send_r18)
+                    .instrument(tracing::info_span!("pixiv"))
+                    .await
+            }
+            None => Ok(None),
+        },
+        LinkType::Pixiv(PixivLinkType::Novel(novel_id)) => match &context.pixiv {
+            Some((pixiv, context)) => {
+                let send_r18 = context.r18 && features.room_pixiv_r18_enabled(room.room_id());
+                self::extractors::pixiv::pixiv_novel(templates, language, pixiv, novel_id, send_r18)
+                    .instrument(tracing::info_span!("pixiv"))
+                    .await
+            }
+            None => Ok(None),
+        },
+        LinkType::BiliBili(link) => {
+            self::extractors::bilibili::bilibili(
+                ev, room, link, bilibili, client, templates, language,
+            )
+            .await
+        }
+        LinkType::GitHub(link) => self::extractors::github::github(link, octocrab, templates).await,
         LinkType::Generic(url) => self::extractors::generic::extract(client, url).await,
         LinkType::CannotBeABase => {
             anyhow::bail!("URL is a cannot-be-a-base!")
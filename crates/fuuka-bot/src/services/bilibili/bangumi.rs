@@ -0,0 +1,53 @@
+use bilibili_api::bangumi::{BangumiId, Season};
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+
+use crate::format::locale::Language;
+
+pub async fn request(
+    bilibili: &bilibili_api::BiliBiliClient,
+    id: BangumiId,
+) -> anyhow::Result<Season> {
+    Ok(bilibili.bangumi_season(id).await?)
+}
+
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: Season,
+    id: BangumiId,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::bilibili::bangumi::{Context, Episode, Rating};
+
+    let (url, episode) = match id {
+        BangumiId::Season(_) => (
+            format!(
+                "https://www.bilibili.com/bangumi/play/ss{season_id}",
+                season_id = resp.season_id
+            ),
+            None,
+        ),
+        BangumiId::Episode(ep_id) => (
+            format!("https://www.bilibili.com/bangumi/play/ep{ep_id}"),
+            resp.episodes.iter().find(|episode| episode.id == ep_id),
+        ),
+    };
+    let context = Context {
+        language,
+        url: &url,
+        title: &resp.title,
+        evaluate: Some(resp.evaluate.as_str()).filter(|evaluate| !evaluate.is_empty()),
+        rating: resp.rating.as_ref().map(|rating| Rating {
+            score: rating.score,
+            count: rating.count,
+        }),
+        progress: resp.new_ep.as_ref().map(|new_ep| new_ep.desc.as_str()),
+        episode: episode.map(|episode| Episode {
+            title: &episode.title,
+            long_title: Some(episode.long_title.as_str()).filter(|title| !title.is_empty()),
+        }),
+    };
+    let body = crate::format::bilibili::bangumi::text::format(env, &context)?;
+    let html_body = crate::format::bilibili::bangumi::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
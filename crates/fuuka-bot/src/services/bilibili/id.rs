@@ -0,0 +1,171 @@
+//! BiliBili video IDs, and conversion between AV and BV IDs.
+
+use std::str::FromStr;
+
+use url::Url;
+
+const XOR_CODE: u64 = 23442827791579;
+const MASK_CODE: u64 = 2251799813685247;
+const MAX_AID: u64 = 1 << 51;
+const BASE: u64 = 58;
+const TABLE: &[u8; 58] = b"FcwAPNKTMug3GV5Lj7EJnHpWsx4tb8haYeviqBz6rkCy12mUSDQX9RdoZf";
+
+/// A BiliBili video, identified by both its AV and BV ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoId {
+    pub aid: u64,
+    pub bvid: String,
+}
+
+impl VideoId {
+    pub fn from_aid(aid: u64) -> Self {
+        Self {
+            aid,
+            bvid: av_to_bv(aid),
+        }
+    }
+}
+
+impl FromStr for VideoId {
+    type Err = anyhow::Error;
+
+    /// Parses `av170001`, `BV17x411w7KC` or a video URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Ok(url) = Url::parse(s) {
+            let id = url
+                .path_segments()
+                .and_then(|mut paths| {
+                    paths
+                        .by_ref()
+                        .find(|path| *path == "video")
+                        .and_then(|_| paths.next())
+                })
+                .ok_or(anyhow::anyhow!("{url} is not a BiliBili video URL!"))?;
+            return id.parse();
+        }
+
+        let aid = if let Some(aid) = s.strip_prefix("av").or(s.strip_prefix("AV")) {
+            aid.parse()
+                .ok()
+                .filter(|aid| (1..MAX_AID).contains(aid))
+                .ok_or(anyhow::anyhow!("Invalid AV ID: {s}"))?
+        } else {
+            bv_to_av(s)
+                .filter(|&aid| aid != 0)
+                .ok_or(anyhow::anyhow!("Invalid BiliBili video ID: {s}"))?
+        };
+        Ok(Self::from_aid(aid))
+    }
+}
+
+/// Converts an AV ID to a BV ID.
+///
+/// `aid` must be less than `2^51`, larger AV IDs don't have a BV ID.
+pub fn av_to_bv(aid: u64) -> String {
+    let mut bytes = *b"BV1000000000";
+    debug_assert!(aid < MAX_AID, "AV ID {aid} is out of range");
+    let mut tmp = (MAX_AID | aid) ^ XOR_CODE;
+    let mut idx = bytes.len() - 1;
+    while tmp > 0 {
+        bytes[idx] = TABLE[(tmp % BASE) as usize];
+        tmp /= BASE;
+        idx -= 1;
+    }
+    bytes.swap(3, 9);
+    bytes.swap(4, 7);
+
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// Converts a BV ID to an AV ID, returns [None] if it's not a valid BV ID.
+pub fn bv_to_av(bvid: &str) -> Option<u64> {
+    let bytes = bvid.as_bytes();
+    if bytes.len() != 12 || !bytes[..3].eq_ignore_ascii_case(b"BV1") {
+        return None;
+    }
+
+    let mut bytes: [u8; 12] = bytes.try_into().ok()?;
+    bytes.swap(3, 9);
+    bytes.swap(4, 7);
+
+    let mut tmp: u64 = 0;
+    for byte in &bytes[3..] {
+        let idx = TABLE.iter().position(|c| c == byte)? as u64;
+        tmp = tmp.checked_mul(BASE)?.checked_add(idx)?;
+    }
+
+    Some((tmp & MASK_CODE) ^ XOR_CODE)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{MAX_AID, VideoId, av_to_bv, bv_to_av};
+
+    #[test]
+    fn av_to_bv_ok() {
+        assert_eq!(av_to_bv(2), "BV1xx411c7mD");
+        assert_eq!(av_to_bv(170001), "BV17x411w7KC");
+        assert_eq!(av_to_bv(1054803170), "BV1mH4y1u7UA");
+    }
+
+    #[test]
+    fn av_to_bv_largest() {
+        let bvid = av_to_bv(MAX_AID - 1);
+        assert_eq!(bvid.len(), 12);
+        assert_eq!(bv_to_av(&bvid), Some(MAX_AID - 1));
+    }
+
+    #[test]
+    fn bv_to_av_ok() {
+        assert_eq!(bv_to_av("BV1xx411c7mD"), Some(2));
+        assert_eq!(bv_to_av("BV17x411w7KC"), Some(170001));
+        assert_eq!(bv_to_av("BV1mH4y1u7UA"), Some(1054803170));
+        assert_eq!(bv_to_av("BV1GJ411x7h7"), Some(80433022));
+    }
+
+    #[test]
+    fn bv_to_av_invalid() {
+        assert_eq!(bv_to_av("BV1GJ411x7h"), None);
+        assert_eq!(bv_to_av("BV1GJ411x7h0"), None);
+        assert_eq!(bv_to_av("av170001"), None);
+    }
+
+    #[test]
+    fn parse_video_id_ok() {
+        let expected = VideoId {
+            aid: 170001,
+            bvid: "BV17x411w7KC".to_string(),
+        };
+
+        assert_eq!("av170001".parse::<VideoId>().unwrap(), expected);
+        assert_eq!("BV17x411w7KC".parse::<VideoId>().unwrap(), expected);
+        assert_eq!(
+            "https://www.bilibili.com/video/BV17x411w7KC/?p=1"
+                .parse::<VideoId>()
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            "https://www.bilibili.com/video/av170001"
+                .parse::<VideoId>()
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn parse_video_id_out_of_range() {
+        assert_eq!("av1".parse::<VideoId>().unwrap().aid, 1);
+        assert_eq!(
+            format!("av{}", MAX_AID - 1).parse::<VideoId>().unwrap().aid,
+            MAX_AID - 1
+        );
+        assert!("av0".parse::<VideoId>().is_err());
+        assert!(format!("av{MAX_AID}").parse::<VideoId>().is_err());
+        assert!("av18446744073709551615".parse::<VideoId>().is_err());
+    }
+}
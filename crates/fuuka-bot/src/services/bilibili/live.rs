@@ -0,0 +1,44 @@
+use bilibili_api::{
+    live::{LiveStatus, RoomInfo},
+    user::UserCard,
+};
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+
+pub struct Live {
+    pub room: RoomInfo,
+    pub streamer: UserCard,
+}
+
+pub async fn request(
+    bilibili: &bilibili_api::BiliBiliClient,
+    room_id: u64,
+) -> anyhow::Result<Live> {
+    let room = bilibili.live_room(room_id).await?;
+    let streamer = bilibili.user_card(room.uid).await?;
+
+    Ok(Live { room, streamer })
+}
+
+pub fn format(env: &minijinja::Environment, resp: Live) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::bilibili::live::{Context, Streamer};
+
+    let context = Context {
+        room_id: resp.room.room_id,
+        title: &resp.room.title,
+        status: match resp.room.live_status {
+            LiveStatus::Live => "live",
+            LiveStatus::Rotating => "rotating",
+            _ => "offline",
+        },
+        viewers: resp.room.online,
+        area: &resp.room.area_name,
+        streamer: Streamer {
+            mid: resp.room.uid,
+            name: &resp.streamer.card.name,
+        },
+    };
+    let body = crate::format::bilibili::live::text::format(env, &context)?;
+    let html_body = crate::format::bilibili::live::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
@@ -1,3 +1,78 @@
+pub mod bangumi;
+pub mod id;
+pub mod live;
+pub mod resource;
+pub mod user;
 pub mod video;
 
+use anyhow::Context;
+use matrix_sdk::{
+    Room,
+    ruma::events::room::message::{OriginalRoomMessageEvent, RoomMessageEventContent},
+};
+use reqwest::header::HeaderValue;
+use std::sync::LazyLock;
+use url::Url;
+
+use self::resource::Resource;
+use crate::format::locale::Language;
+
 static USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
+
+static USER_AGENT_HEADER_VALUE: LazyLock<HeaderValue> =
+    LazyLock::new(|| HeaderValue::from_static(USER_AGENT));
+
+/// Follows the redirect of a `b23.tv` short link, returning the resolved URL.
+pub async fn resolve_short_link(client: &reqwest::Client, url: Url) -> anyhow::Result<Url> {
+    let resp = client
+        .get(url)
+        .header(reqwest::header::USER_AGENT, USER_AGENT_HEADER_VALUE.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok(resp.url().clone())
+}
+
+/// Looks up a BiliBili resource, returning the formatted message.
+///
+/// Videos are sent directly with the cover attached, in which case [None] is returned.
+pub async fn lookup(
+    ev: &OriginalRoomMessageEvent,
+    room: &Room,
+    bilibili: &bilibili_api::BiliBiliClient,
+    client: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
+    resource: Resource,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    let content = match resource {
+        Resource::Video(id) => {
+            let video = self::video::request(bilibili, client, &id)
+                .await
+                .context(format!("Failed to query BiliBili video {}", id.bvid))?;
+            self::video::send(ev, room, client, env, language, video).await?;
+            return Ok(None);
+        }
+        Resource::User(mid) => {
+            let user = self::user::request(bilibili, mid)
+                .await
+                .context(format!("Failed to query BiliBili user {mid}"))?;
+            self::user::format(env, language, user)
+        }
+        Resource::Bangumi(id) => {
+            let season = self::bangumi::request(bilibili, id)
+                .await
+                .context(format!("Failed to query BiliBili bangumi {id:?}"))?;
+            self::bangumi::format(env, language, season, id)
+        }
+        Resource::Live(room_id) => {
+            let live = self::live::request(bilibili, room_id)
+                .await
+                .context(format!("Failed to query BiliBili live room {room_id}"))?;
+            self::live::format(env, live)
+        }
+    }?;
+
+    Ok(Some(content))
+}
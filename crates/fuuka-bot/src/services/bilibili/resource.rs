@@ -0,0 +1,159 @@
+//! Parsing of BiliBili resources from IDs and URLs.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use bilibili_api::bangumi::BangumiId;
+use url::{Host, Url};
+
+use super::id::VideoId;
+
+/// A BiliBili resource that can be looked up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resource {
+    Video(VideoId),
+    /// An user, identified by their UID.
+    User(u64),
+    Bangumi(BangumiId),
+    /// A live room, identified by its room ID.
+    Live(u64),
+}
+
+impl Resource {
+    /// Parses a BiliBili URL, returns [None] if the URL is not a supported resource.
+    pub fn from_url(url: &Url) -> anyhow::Result<Option<Self>> {
+        let Some(Host::Domain(host)) = url.host() else {
+            return Ok(None);
+        };
+        let Some(paths) = url.path_segments() else {
+            return Ok(None);
+        };
+        let paths: Vec<&str> = paths.filter(|p| !p.is_empty()).collect();
+
+        let resource = match (host, paths.as_slice()) {
+            ("www.bilibili.com" | "bilibili.com" | "m.bilibili.com", ["video", id, ..]) => {
+                Self::Video(id.parse()?)
+            }
+            (
+                "www.bilibili.com" | "bilibili.com" | "m.bilibili.com",
+                ["bangumi", "play", id, ..],
+            ) => Self::Bangumi(parse_bangumi_id(id)?),
+            ("space.bilibili.com", [mid, ..]) | ("m.bilibili.com", ["space", mid, ..]) => {
+                Self::User(mid.parse().context("Failed to parse BiliBili user ID")?)
+            }
+            ("live.bilibili.com", [room_id, ..]) => match room_id.parse() {
+                Ok(room_id) => Self::Live(room_id),
+                Err(_) => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(resource))
+    }
+}
+
+impl FromStr for Resource {
+    type Err = anyhow::Error;
+
+    /// Parses `av170001`, `BV17x411w7KC`, `ss12345`, `ep12345`, `uid:2`, `live:21452505` or a supported URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(mid) = s.strip_prefix("uid:").or(s.strip_prefix("UID:")) {
+            return Ok(Self::User(
+                mid.parse().context("Failed to parse BiliBili user ID")?,
+            ));
+        }
+
+        if let Some(room_id) = s.strip_prefix("live:") {
+            return Ok(Self::Live(
+                room_id
+                    .parse()
+                    .context("Failed to parse BiliBili room ID")?,
+            ));
+        }
+
+        if let Ok(url) = Url::parse(s) {
+            return Self::from_url(&url)?
+                .ok_or(anyhow::anyhow!("{url} is not a supported BiliBili URL!"));
+        }
+
+        if let Ok(id) = parse_bangumi_id(s) {
+            return Ok(Self::Bangumi(id));
+        }
+
+        Ok(Self::Video(s.parse()?))
+    }
+}
+
+/// Parses `ss12345` or `ep12345`.
+fn parse_bangumi_id(s: &str) -> anyhow::Result<BangumiId> {
+    if let Some(season_id) = s.strip_prefix("ss") {
+        Ok(BangumiId::Season(
+            season_id
+                .parse()
+                .context("Failed to parse BiliBili season ID")?,
+        ))
+    } else if let Some(ep_id) = s.strip_prefix("ep") {
+        Ok(BangumiId::Episode(
+            ep_id
+                .parse()
+                .context("Failed to parse BiliBili episode ID")?,
+        ))
+    } else {
+        anyhow::bail!("{s} is not a BiliBili bangumi ID!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bilibili_api::bangumi::BangumiId;
+    use pretty_assertions::assert_eq;
+
+    use super::Resource;
+    use crate::services::bilibili::id::VideoId;
+
+    #[test]
+    fn parse_resource_ok() {
+        assert_eq!(
+            "av170001".parse::<Resource>().unwrap(),
+            Resource::Video(VideoId::from_aid(170001))
+        );
+        assert_eq!(
+            "ep805450".parse::<Resource>().unwrap(),
+            Resource::Bangumi(BangumiId::Episode(805450))
+        );
+        assert_eq!("uid:2".parse::<Resource>().unwrap(), Resource::User(2));
+        assert_eq!(
+            "live:21452505".parse::<Resource>().unwrap(),
+            Resource::Live(21452505)
+        );
+    }
+
+    #[test]
+    fn parse_resource_from_url_ok() {
+        assert_eq!(
+            "https://space.bilibili.com/486906719/video"
+                .parse::<Resource>()
+                .unwrap(),
+            Resource::User(486906719)
+        );
+        assert_eq!(
+            "https://www.bilibili.com/bangumi/play/ss45969"
+                .parse::<Resource>()
+                .unwrap(),
+            Resource::Bangumi(BangumiId::Season(45969))
+        );
+        assert_eq!(
+            "https://live.bilibili.com/21452505?spm_id_from=333.1007"
+                .parse::<Resource>()
+                .unwrap(),
+            Resource::Live(21452505)
+        );
+        assert!(
+            "https://www.bilibili.com/read/cv1"
+                .parse::<Resource>()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use bilibili_api::user::{SpaceVideo, UserCard};
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+
+use crate::format::locale::Language;
+
+/// How many latest videos to include.
+const LATEST_VIDEOS: u32 = 5;
+
+pub struct User {
+    pub mid: u64,
+    pub card: UserCard,
+    pub videos: Vec<SpaceVideo>,
+}
+
+pub async fn request(bilibili: &bilibili_api::BiliBiliClient, mid: u64) -> anyhow::Result<User> {
+    let card = bilibili.user_card(mid).await?;
+    // The video list is often rejected by risk control, still show the card in that case.
+    let videos = bilibili
+        .user_videos(mid, LATEST_VIDEOS)
+        .await
+        .inspect_err(|e| tracing::warn!("Failed to request latest videos of {mid}: {e}"))
+        .unwrap_or_default();
+
+    Ok(User { mid, card, videos })
+}
+
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: User,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::bilibili::user::{Context, Video};
+
+    let videos: Vec<_> = resp
+        .videos
+        .iter()
+        .map(|video| Video {
+            bvid: &video.bvid,
+            title: &video.title,
+        })
+        .collect();
+    let context = Context {
+        language,
+        mid: resp.mid,
+        name: &resp.card.card.name,
+        sign: Some(resp.card.card.sign.as_str()).filter(|sign| !sign.is_empty()),
+        followers: resp.card.follower,
+        videos_count: resp.card.archive_count,
+        videos: &videos,
+    };
+    let body = crate::format::bilibili::user::text::format(env, &context)?;
+    let html_body = crate::format::bilibili::user::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
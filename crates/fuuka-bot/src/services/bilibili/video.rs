@@ -1,22 +1,92 @@
-use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+use matrix_sdk::ruma::events::{
+    Mentions,
+    room::message::{
+        AddMentions, ForwardThread, OriginalRoomMessageEvent, RoomMessageEventContent,
+        TextMessageEventContent,
+    },
+};
+use matrix_sdk::{
+    Room,
+    attachment::{AttachmentConfig, AttachmentInfo, BaseImageInfo},
+    room::reply::{EnforceThread, Reply},
+};
+use mime::Mime;
 use regex::Regex;
-use reqwest::header::HeaderValue;
 use serde::Deserialize;
+use std::str::FromStr;
 use std::sync::LazyLock;
 use time::OffsetDateTime;
+use url::Url;
+
+use super::id::VideoId;
+use crate::format::locale::Language;
 
 static REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(?:window\.__INITIAL_STATE__\s*=)\s*(?P<json>\{(?s:.+)\})\s*(?:;)").unwrap()
 });
 
-static USER_AGENT_HEADER_VALUE: LazyLock<HeaderValue> =
-    LazyLock::new(|| HeaderValue::from_static(super::USER_AGENT));
+/// Requests video info from the BiliBili web API, falling back to scraping the video page.
+pub async fn request(
+    bilibili: &bilibili_api::BiliBiliClient,
+    client: &reqwest::Client,
+    id: &VideoId,
+) -> anyhow::Result<Video> {
+    match request_api(bilibili, id).await {
+        Ok(video) => Ok(video),
+        Err(e) => {
+            tracing::warn!("Failed to request BiliBili API, falling back to scraping: {e:#}");
+            request_page(client, id).await
+        }
+    }
+}
+
+async fn request_api(
+    bilibili: &bilibili_api::BiliBiliClient,
+    id: &VideoId,
+) -> anyhow::Result<Video> {
+    let view = bilibili.video_view(&id.bvid).await?;
+    let tags = bilibili.video_tags(&id.bvid).await?;
+
+    Ok(Video {
+        data: VideoData {
+            aid: view.aid,
+            bvid: view.bvid,
+            title: view.title,
+            desc: view.desc,
+            pic: view.pic,
+            pubdate: OffsetDateTime::from_unix_timestamp(view.pubdate).ok(),
+            owner: Owner {
+                mid: view.owner.mid,
+                name: view.owner.name,
+            },
+            stat: Stat {
+                view: view.stat.view,
+                danmaku: view.stat.danmaku,
+                reply: view.stat.reply,
+                favorite: view.stat.favorite,
+                coin: view.stat.coin,
+                share: view.stat.share,
+                like: view.stat.like,
+            },
+        },
+        tags: tags
+            .into_iter()
+            .map(|tag| Tag {
+                tag_name: tag.tag_name,
+            })
+            .collect(),
+    })
+}
 
-pub async fn request(client: &reqwest::Client, id: &str) -> anyhow::Result<Video> {
-    let url = format!("https://www.bilibili.com/video/{id}");
+/// Scrapes `window.__INITIAL_STATE__` from the video page.
+async fn request_page(client: &reqwest::Client, id: &VideoId) -> anyhow::Result<Video> {
+    let url = format!("https://www.bilibili.com/video/{bvid}", bvid = id.bvid);
     let body = client
         .get(url)
-        .header(reqwest::header::USER_AGENT, USER_AGENT_HEADER_VALUE.clone())
+        .header(
+            reqwest::header::USER_AGENT,
+            super::USER_AGENT_HEADER_VALUE.clone(),
+        )
         .send()
         .await?
         .error_for_status()?
@@ -30,8 +100,87 @@ pub async fn request(client: &reqwest::Client, id: &str) -> anyhow::Result<Video
     Ok(serde_json::from_str(json_str)?)
 }
 
-pub fn format(resp: Video, _prefix: bool) -> anyhow::Result<RoomMessageEventContent> {
-    use crate::format::ENVIRONMENT;
+/// Sends the video info with the cover attached, or as text if the cover failed to download.
+pub async fn send(
+    ev: &OriginalRoomMessageEvent,
+    room: &Room,
+    http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
+    video: Video,
+) -> anyhow::Result<()> {
+    let (body, formatted_body) = format(env, language, &video, false)?;
+
+    match download_cover(http, &video.data.pic).await {
+        Ok((filename, image)) => {
+            let info = crate::matrix::imageinfo(&image)?;
+
+            let config = AttachmentConfig::new()
+                .info(AttachmentInfo::Image(BaseImageInfo {
+                    height: info.height,
+                    width: info.width,
+                    size: info.size,
+                    blurhash: None,
+                    is_animated: Some(false),
+                }))
+                .caption(Some(TextMessageEventContent::html(body, formatted_body)))
+                .mentions(Some(Mentions::with_user_ids([ev.sender.clone()])))
+                .reply(Some(Reply {
+                    event_id: ev.event_id.clone(),
+                    enforce_thread: EnforceThread::MaybeThreaded,
+                    add_mentions: AddMentions::Yes,
+                }));
+
+            let content_type =
+                Mime::from_str(file_format::FileFormat::from_bytes(&image).media_type())?;
+
+            room.send_attachment(filename, &content_type, image, config)
+                .await?;
+        }
+        Err(e) => {
+            tracing::warn!(
+                "Failed to download the cover of {bvid}, sending text only: {e:#}",
+                bvid = video.data.bvid
+            );
+            let content = RoomMessageEventContent::text_html(body, formatted_body);
+            room.send(content.make_reply_to(ev, ForwardThread::No, AddMentions::Yes))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads the cover, returning its filename and content.
+async fn download_cover(http: &reqwest::Client, url: &str) -> anyhow::Result<(String, Vec<u8>)> {
+    let url = Url::parse(url)?;
+    let filename = url
+        .path_segments()
+        .and_then(|mut path| path.next_back())
+        .unwrap_or("cover.jpg")
+        .to_string();
+
+    let image = http
+        .get(url)
+        .header(reqwest::header::REFERER, "https://www.bilibili.com")
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?
+        .to_vec();
+    // Make sure the cover is a valid image before sending it.
+    imagesize::blob_size(&image)?;
+
+    Ok((filename, image))
+}
+
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: &Video,
+    _prefix: bool,
+) -> anyhow::Result<(String, String)> {
     use crate::format::bilibili::video::{Author, Context, Counts};
 
     let tags: Vec<_> = resp.tags.iter().map(|tag| tag.tag_name.as_str()).collect();
@@ -47,7 +196,9 @@ pub fn format(resp: Video, _prefix: bool) -> anyhow::Result<RoomMessageEventCont
         ..
     } = resp.data.stat;
     let context = Context {
-        id: &resp.data.bvid,
+        language,
+        bvid: &resp.data.bvid,
+        aid: resp.data.aid,
         title: &resp.data.title,
         description: match resp.data.desc.as_str() {
             "-" => None,
@@ -68,10 +219,10 @@ pub fn format(resp: Video, _prefix: bool) -> anyhow::Result<RoomMessageEventCont
             share,
         },
     };
-    let body: String = crate::format::bilibili::video::text::format(&ENVIRONMENT, &context)?;
-    let html_body = crate::format::bilibili::video::html::format(&ENVIRONMENT, &context)?;
+    let body: String = crate::format::bilibili::video::text::format(env, &context)?;
+    let html_body = crate::format::bilibili::video::html::format(env, &context)?;
 
-    Ok(RoomMessageEventContent::text_html(body, html_body))
+    Ok((body, html_body))
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -92,9 +243,12 @@ pub struct Tag {
 #[allow(missing_docs)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoData {
+    pub aid: u64,
     pub bvid: String,
     pub title: String,
     pub desc: String,
+    /// The URL of the cover.
+    pub pic: String,
     #[serde(with = "time::serde::timestamp::option")]
     pub pubdate: Option<OffsetDateTime>,
     pub owner: Owner,
@@ -23,6 +23,7 @@ use crate::{
 
 pub mod models;
 pub mod pr_tracker;
+pub mod preview;
 
 #[derive(Clone)]
 pub struct Context {
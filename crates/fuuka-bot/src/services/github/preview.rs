@@ -0,0 +1,260 @@
+//! Link previews for GitHub repositories, commits, issues, pull requests, releases and files.
+
+use std::ops::RangeInclusive;
+
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+use octocrab::{Octocrab, models::IssueState};
+
+use crate::{
+    config::RepositoryParts,
+    services::github::{Params, models::PullRequestState},
+};
+
+/// Maximum lines of a file to include in a preview.
+const MAX_LINES: usize = 50;
+
+pub async fn repository(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::repository::Context;
+
+    let RepositoryParts { owner, repo } = repository;
+    let resp = octocrab.repos(owner, repo).get().await?;
+
+    let url = resp
+        .html_url
+        .map(|url| url.to_string())
+        .unwrap_or_else(|| format!("https://github.com/{owner}/{repo}"));
+    let context = Context {
+        url: &url,
+        owner,
+        repo,
+        description: resp.description.as_deref(),
+        language: resp.language.as_ref().and_then(|lang| lang.as_str()),
+        stars: resp.stargazers_count.unwrap_or_default(),
+        forks: resp.forks_count.unwrap_or_default(),
+    };
+    let body = crate::format::github::repository::text::format(env, &context)?;
+    let html_body = crate::format::github::repository::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+pub async fn commit(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+    sha: &str,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::commit::{Context, Stats};
+
+    let RepositoryParts { owner, repo } = repository;
+    let resp = octocrab.commits(owner, repo).get(sha).await?;
+
+    let author = resp
+        .author
+        .as_ref()
+        .map(|author| author.login.as_str())
+        .or(resp
+            .commit
+            .author
+            .as_ref()
+            .map(|author| author.user.name.as_str()));
+    let context = Context {
+        url: &resp.html_url,
+        owner,
+        repo,
+        sha: &resp.sha,
+        summary: resp.commit.message.lines().next().unwrap_or_default(),
+        author,
+        stats: resp.stats.as_ref().map(|stats| Stats {
+            additions: stats.additions.unwrap_or_default(),
+            deletions: stats.deletions.unwrap_or_default(),
+        }),
+    };
+    let body = crate::format::github::commit::text::format(env, &context)?;
+    let html_body = crate::format::github::commit::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+pub async fn issue(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+    number: u64,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::issue::Context;
+
+    let RepositoryParts { owner, repo } = repository;
+    let resp = octocrab.issues(owner, repo).get(number).await?;
+
+    let url = resp.html_url.to_string();
+    let labels: Vec<_> = resp
+        .labels
+        .iter()
+        .map(|label| label.name.as_str())
+        .collect();
+    let context = Context {
+        url: &url,
+        owner,
+        repo,
+        number: resp.number,
+        title: &resp.title,
+        author: &resp.user.login,
+        state: match resp.state {
+            IssueState::Open => "open",
+            _ => "closed",
+        },
+        pull_request: resp.pull_request.is_some(),
+        labels: &labels,
+    };
+    let body = crate::format::github::issue::text::format(env, &context)?;
+    let html_body = crate::format::github::issue::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+pub async fn pull_request(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+    pr_number: i32,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::issue::Context;
+
+    let resp = crate::services::github::pull_request(
+        octocrab,
+        Params {
+            repository: repository.clone(),
+            pr_number,
+        },
+    )
+    .await?;
+
+    let RepositoryParts { owner, repo } = repository;
+    let url = resp.permalink.to_string();
+    let context = Context {
+        url: &url,
+        owner,
+        repo,
+        number: resp.number.try_into()?,
+        title: &resp.title,
+        author: &resp.author.login,
+        state: match resp.state {
+            PullRequestState::OPEN => "open",
+            PullRequestState::CLOSED { .. } => "closed",
+            PullRequestState::MERGED { .. } => "merged",
+        },
+        pull_request: true,
+        labels: &[],
+    };
+    let body = crate::format::github::issue::text::format(env, &context)?;
+    let html_body = crate::format::github::issue::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+pub async fn release(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+    tag: Option<&str>,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::release::Context;
+
+    let RepositoryParts { owner, repo } = repository;
+    let handler = octocrab.repos(owner, repo);
+    let releases = handler.releases();
+    let resp = match tag {
+        Some(tag) => releases.get_by_tag(tag).await?,
+        None => releases.get_latest().await?,
+    };
+
+    let url = resp.html_url.to_string();
+    let context = Context {
+        url: &url,
+        owner,
+        repo,
+        tag: &resp.tag_name,
+        name: resp.name.as_deref().filter(|name| !name.is_empty()),
+        author: resp.author.as_ref().map(|author| author.login.as_str()),
+        prerelease: resp.prerelease,
+    };
+    let body = crate::format::github::release::text::format(env, &context)?;
+    let html_body = crate::format::github::release::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
+
+pub async fn blob(
+    env: &minijinja::Environment,
+    octocrab: &Octocrab,
+    repository: &RepositoryParts,
+    reference: &str,
+    path: &str,
+    lines: Option<RangeInclusive<usize>>,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::github::blob::{Context, Lines};
+
+    let RepositoryParts { owner, repo } = repository;
+    // Only the first MAX_LINES lines are shown, so the header and URL refer to those.
+    let lines = lines.map(|lines| {
+        let start = *lines.start();
+        start..=(*lines.end()).min(start + MAX_LINES - 1)
+    });
+
+    let code = match &lines {
+        Some(lines) => {
+            let mut resp = octocrab
+                .repos(owner, repo)
+                .get_content()
+                .path(path)
+                .r#ref(reference)
+                .send()
+                .await?;
+            let Some(content) = resp.take_items().into_iter().next() else {
+                anyhow::bail!("{path} is not a file!");
+            };
+            content.decoded_content().map(|content| {
+                content
+                    .lines()
+                    .skip(lines.start() - 1)
+                    .take(lines.end() - lines.start() + 1)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        None => None,
+    };
+
+    let url = match &lines {
+        Some(lines) => format!(
+            "https://github.com/{owner}/{repo}/blob/{reference}/{path}#L{start}-L{end}",
+            start = lines.start(),
+            end = lines.end()
+        ),
+        None => format!("https://github.com/{owner}/{repo}/blob/{reference}/{path}"),
+    };
+    let language = std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str());
+    let context = Context {
+        url: &url,
+        owner,
+        repo,
+        path,
+        lines: lines.map(|lines| Lines {
+            start: *lines.start(),
+            end: *lines.end(),
+        }),
+        language,
+        code: code.as_deref().filter(|code| !code.is_empty()),
+    };
+    let body = crate::format::github::blob::text::format(env, &context)?;
+    let html_body = crate::format::github::blob::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
@@ -1,16 +1,20 @@
 use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
 
-pub fn format(resp: hitokoto_api::Response) -> RoomMessageEventContent {
-    let from_who = resp.from_who.unwrap_or_default();
+pub fn format(
+    env: &minijinja::Environment,
+    resp: hitokoto_api::Response,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::hitokoto::quote::Context;
 
-    RoomMessageEventContent::text_html(
-        format!(
-            "『{0}』——{1}「{2}」\nFrom https://hitokoto.cn/?uuid={3}",
-            resp.hitokoto, from_who, resp.from, resp.uuid
-        ),
-        format!(
-            "<p><b>『{0}』</b><br/>——{1}「{2}」</p><p>From https://hitokoto.cn/?uuid={3}</p>",
-            resp.hitokoto, from_who, resp.from, resp.uuid
-        ),
-    )
+    let context = Context {
+        uuid: &resp.uuid,
+        hitokoto: &resp.hitokoto,
+        from: &resp.from,
+        from_who: resp.from_who.as_deref(),
+    };
+
+    let body = crate::format::hitokoto::quote::text::format(env, &context)?;
+    let html_body = crate::format::hitokoto::quote::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
 }
@@ -0,0 +1,137 @@
+//! Posts Pixiv rankings to rooms on schedule.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use matrix_sdk::{
+    attachment::{GalleryConfig, GalleryItemInfo},
+    ruma::events::room::message::{RoomMessageEventContent, TextMessageEventContent},
+};
+use mime::Mime;
+use pixiv_ajax_api::PixivClient;
+
+use crate::config::{PixivAIFilter, RankingDigestConfig};
+use crate::format::locale::Language;
+use crate::services::github::pr_tracker::streams::CronStream;
+
+/// Maximum thumbnails to attach, the rest of the items are still listed.
+const MAX_THUMBNAILS: usize = 10;
+
+/// Posts the ranking on every tick of the configured cron schedule.
+///
+/// R-18 rankings are only posted if `send_r18` is true.
+pub(crate) async fn run(
+    client: matrix_sdk::Client,
+    pixiv: Arc<PixivClient>,
+    http: reqwest::Client,
+    env: Arc<minijinja::Environment<'static>>,
+    language: Language,
+    digest: RankingDigestConfig,
+    send_r18: bool,
+    ai_filter: PixivAIFilter,
+) {
+    let room_id = &digest.room;
+    if digest.mode.is_r18() && !send_r18 {
+        tracing::warn!(
+            %room_id,
+            "Not scheduling the {mode:?} ranking as R-18 is not enabled in the room",
+            mode = digest.mode
+        );
+        return;
+    }
+
+    let cron = CronStream::new(digest.cron.clone());
+    loop {
+        cron.wait_for_next_tick().await;
+
+        tracing::debug!(%room_id, "Posting the {mode:?} ranking", mode = digest.mode);
+        if let Err(e) = post(&client, &pixiv, &http, &env, language, &digest, ai_filter).await {
+            tracing::warn!(%room_id, "Failed to post the ranking digest: {e:#}");
+        }
+    }
+}
+
+async fn post(
+    client: &matrix_sdk::Client,
+    pixiv: &PixivClient,
+    http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
+    digest: &RankingDigestConfig,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<()> {
+    let Some(room) = client.get_room(&digest.room) else {
+        anyhow::bail!("The bot is not in the room");
+    };
+
+    let items = super::ranking::request(
+        pixiv,
+        digest.mode,
+        digest.content,
+        None,
+        0,
+        digest.count,
+        ai_filter,
+    )
+    .await?;
+    let (body, html_body) = super::ranking::format(
+        env,
+        language,
+        &items,
+        digest.mode,
+        digest.content,
+        None,
+        ai_filter,
+    )?;
+
+    let mut gallery_items = Vec::new();
+    if digest.thumbnails {
+        for item in items.iter().take(MAX_THUMBNAILS) {
+            let (filename, image) = match super::illust::download(http, &item.url).await {
+                Ok(image) => image,
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to download the thumbnail of {illust_id}: {e:#}",
+                        illust_id = item.illust_id
+                    );
+                    continue;
+                }
+            };
+            let attachment_info = match super::illust::image_attachment_info(&image) {
+                Ok(attachment_info) => attachment_info,
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to read the thumbnail of {illust_id}: {e:#}",
+                        illust_id = item.illust_id
+                    );
+                    continue;
+                }
+            };
+            let content_type =
+                Mime::from_str(file_format::FileFormat::from_bytes(&image).media_type())?;
+            gallery_items.push(GalleryItemInfo {
+                filename,
+                content_type,
+                data: image,
+                attachment_info,
+                caption: None,
+                thumbnail: None,
+            });
+        }
+    }
+
+    // Send the ranking as text if there are no thumbnails to attach.
+    if gallery_items.is_empty() {
+        room.send(RoomMessageEventContent::text_html(body, html_body))
+            .await?;
+
+        return Ok(());
+    }
+
+    let gallery = gallery_items.into_iter().fold(
+        GalleryConfig::new().caption(Some(TextMessageEventContent::html(body, html_body))),
+        GalleryConfig::add_item,
+    );
+    room.send_gallery(gallery).await?;
+
+    Ok(())
+}
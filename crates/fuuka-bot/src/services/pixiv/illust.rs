@@ -1,37 +1,75 @@
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 
+use crate::config::{PixivAIFilter, PixivImageSize};
+use crate::format::locale::Language;
 use matrix_sdk::ruma::events::{
     Mentions,
-    room::message::{AddMentions, OriginalRoomMessageEvent, TextMessageEventContent},
+    room::message::{
+        AddMentions, GalleryItemType, GalleryMessageEventContent, ImageMessageEventContent,
+        MessageType, OriginalRoomMessageEvent, RoomMessageEventContentWithoutRelation,
+        TextMessageEventContent,
+    },
 };
 use matrix_sdk::{
     Room,
-    attachment::{AttachmentConfig, AttachmentInfo, BaseImageInfo},
+    attachment::{AttachmentConfig, AttachmentInfo, BaseImageInfo, GalleryConfig, GalleryItemInfo},
     room::reply::{EnforceThread, Reply},
     ruma::RoomId,
 };
 use mime::Mime;
-use pixiv_ajax_api::{common::Restriction, illust::IllustInfo};
+use pixiv_ajax_api::{
+    common::{AIType, Restriction},
+    illust::{IllustInfo, IllustType},
+};
+
+/// Whether the illust is R-18 or R-18G.
+fn is_r18(restriction: Restriction) -> bool {
+    // R18 = 1, R18G = 2, General = 0
+    match restriction {
+        Restriction::General => false,
+        Restriction::R18 => true,
+        Restriction::R18G => true,
+        _ => false,
+    }
+}
+
+/// Returns the name of the restriction shown to users.
+pub(super) fn restriction_name(restriction: Restriction) -> &'static str {
+    match restriction {
+        Restriction::R18 => "R-18",
+        Restriction::R18G => "R-18G",
+        _ => "General",
+    }
+}
 
+fn ai_type_name(ai_type: AIType) -> &'static str {
+    match ai_type {
+        AIType::NotAI => "not-ai",
+        AIType::AI => "ai",
+        _ => "unknown",
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
     resp: IllustInfo,
     context: &super::Context,
     send_r18: bool,
+    ai_filter: PixivAIFilter,
     room_id: &RoomId,
+    shown_pages: Option<usize>,
     _prefix: bool,
 ) -> Option<(String, String)> {
-    // R18 = 1, R18G = 2, General = 0
-    let r18 = match resp.restriction {
-        Restriction::General => false,
-        Restriction::R18 => true,
-        Restriction::R18G => true,
-        _ => false,
-    };
-    if r18 && !send_r18 {
+    if is_r18(resp.restriction) && !send_r18 {
         return None;
     };
+    if ai_filter.hides(resp.ai_type) {
+        return None;
+    }
 
-    use crate::format::ENVIRONMENT;
     use crate::format::pixiv::illust::{Author, Context, Tag};
 
     let tags: Vec<_> = resp
@@ -40,7 +78,7 @@ pub fn format(
         .iter()
         .map(|tag| Tag {
             original: &tag.tag,
-            translated: tag.translation.get("en").map(String::as_str),
+            translated: super::translated(&tag.translation, language),
         })
         .collect();
 
@@ -48,88 +86,405 @@ pub fn format(
         .tag_triggers
         .check_for_tag_triggers(&resp.tags, room_id);
 
+    let description = crate::format::sanitize::html_to_text(&resp.description);
+
     let context = Context {
+        language,
         id: resp.id,
         title: &resp.title,
+        description: &description,
         tags: &tags,
         author: Author {
             id: resp.user_id,
             name: &resp.user_name,
         },
-        triggers: triggers.as_slice(),
+        triggers: &triggers,
+        ai: ai_filter.labels(resp.ai_type),
+        ai_type: ai_type_name(resp.ai_type),
+        restriction: restriction_name(resp.restriction),
+        create_date: &resp.create_date.0,
+        page_count: resp.page_count,
+        bookmark_count: resp.bookmark_count,
+        like_count: resp.like_count,
+        view_count: resp.view_count,
+        shown_pages,
     };
 
-    let body: String = crate::format::pixiv::illust::text::format(&ENVIRONMENT, &context).ok()?;
-    let html_body = crate::format::pixiv::illust::html::format(&ENVIRONMENT, &context).ok()?;
+    let body: String = crate::format::pixiv::illust::text::format(env, &context).ok()?;
+    let html_body = crate::format::pixiv::illust::html::format(env, &context).ok()?;
 
     Some((body, html_body))
 }
 
+/// Maximum pages to send at once.
+const MAX_PAGES: usize = 10;
+
+/// Sends an illust with its pages attached in `image_size`.
+///
+/// If `pages` is [None], up to the first [MAX_PAGES] pages are sent,
+/// otherwise it should contain at most [MAX_PAGES] pages.
+///
+/// If the illust is R-18 and `send_r18` is false, a pixelated preview is sent
+/// instead when `r18_preview` is true, otherwise nothing is sent.
+#[allow(clippy::too_many_arguments)]
 pub async fn send(
     ev: &OriginalRoomMessageEvent,
     room: &Room,
     pixiv: &pixiv_ajax_api::PixivClient,
     http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
     context: &super::Context,
     illust_id: i32,
     send_r18: bool,
+    r18_preview: bool,
+    ai_filter: PixivAIFilter,
+    image_size: PixivImageSize,
+    pages: Option<RangeInclusive<usize>>,
 ) -> anyhow::Result<()> {
-    let resp = pixiv.illust_info(illust_id).with_lang("zh").await?;
+    let resp = pixiv
+        .illust_info(illust_id)
+        .with_lang(language.pixiv())
+        .await?;
     let room_id = room.room_id();
+    let page_count = resp.page_count as usize;
+    let animated = resp.illust_type == IllustType::Animation;
 
-    let url = resp.urls.original.clone();
+    let shown_pages = (pages.is_none() && page_count > MAX_PAGES).then_some(MAX_PAGES);
+    let pages = match pages {
+        Some(pages) => {
+            if pages.end() - pages.start() + 1 > MAX_PAGES {
+                anyhow::bail!("At most {MAX_PAGES} pages can be sent at once!");
+            }
+            if *pages.end() > page_count {
+                anyhow::bail!("Illust {illust_id} only has {page_count} page(s)!");
+            }
+            pages
+        }
+        None => 1..=MAX_PAGES.min(page_count),
+    };
 
-    if let Some((body, formatted_body)) =
-        crate::services::pixiv::illust::format(resp.clone(), context, send_r18, room_id, false)
-    {
-        use url::Url;
-
-        let url = Url::parse(&url)?;
-        let filename = url
-            .path_segments()
-            .and_then(|mut path| path.next_back())
-            .unwrap_or("file.png")
-            .to_string();
-
-        let image = http
-            .get(url)
-            .header(reqwest::header::REFERER, "https://www.pixiv.net")
-            .send()
-            .await?
-            .error_for_status()?
-            .bytes()
+    if r18_preview && !send_r18 && is_r18(resp.restriction) && !ai_filter.hides(resp.ai_type) {
+        return super::withheld::send(ev, room, http, env, language, &resp).await;
+    }
+
+    let first_page = select_url(
+        image_size,
+        &resp.urls.small,
+        &resp.urls.regular,
+        &resp.urls.original,
+    );
+
+    let Some((body, formatted_body)) = crate::services::pixiv::illust::format(
+        env,
+        language,
+        resp,
+        context,
+        send_r18,
+        ai_filter,
+        room_id,
+        shown_pages,
+        false,
+    ) else {
+        tracing::debug!(
+            "Not sending response because the requested illust is marked R-18 or AI-generated."
+        );
+        return Ok(());
+    };
+
+    let caption = TextMessageEventContent::html(body, formatted_body);
+    let mentions = Mentions::with_user_ids([ev.sender.clone()]);
+    let reply = Reply {
+        event_id: ev.event_id.clone(),
+        enforce_thread: EnforceThread::MaybeThreaded,
+        add_mentions: AddMentions::Yes,
+    };
+
+    if animated {
+        match super::ugoira::download(pixiv, http, illust_id).await {
+            Ok((_, image)) if image.len() as u64 > context.max_upload_size => {
+                tracing::debug!(
+                    "Ugoira {illust_id} is larger than the maximum upload size, sending the first frame instead"
+                );
+            }
+            Ok((filename, image)) => {
+                let info = crate::matrix::imageinfo(&image)?;
+                let config = AttachmentConfig::new()
+                    .info(AttachmentInfo::Image(BaseImageInfo {
+                        height: info.height,
+                        width: info.width,
+                        size: info.size,
+                        blurhash: None,
+                        is_animated: Some(true),
+                    }))
+                    .caption(Some(caption))
+                    .mentions(Some(mentions))
+                    .reply(Some(reply));
+
+                room.send_attachment(filename, &mime::IMAGE_GIF, image, config)
+                    .await?;
+
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to assemble ugoira {illust_id}, sending the first frame instead: {e:#}"
+                );
+            }
+        }
+    }
+
+    let urls = if pages == (1..=1) {
+        // The first page is already known, no need to query all pages.
+        vec![first_page]
+    } else {
+        pixiv
+            .illust_pages(illust_id)
             .await?
-            .to_vec();
+            .into_iter()
+            .skip(pages.start() - 1)
+            .take(pages.end() - pages.start() + 1)
+            .map(|page| {
+                select_url(
+                    image_size,
+                    &page.urls.small,
+                    &page.urls.regular,
+                    &page.urls.original,
+                )
+            })
+            .collect()
+    };
 
-        let info = crate::matrix::imageinfo(&image)?;
+    let mut images = Vec::with_capacity(urls.len());
+    for (url, fallback) in urls {
+        let image = match download_limited(http, &url, context.max_upload_size).await? {
+            Some(image) => image,
+            None => {
+                let Some(fallback) = fallback else {
+                    anyhow::bail!("Image {url} is larger than the maximum upload size!");
+                };
+                tracing::debug!("Image {url} is too large, falling back to {fallback}");
+                download_limited(http, &fallback, context.max_upload_size)
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Image {fallback} is larger than the maximum upload size!")
+                    })?
+            }
+        };
+        images.push(image);
+    }
+
+    // Uploads can only be reused in unencrypted rooms,
+    // as encrypted attachments are encrypted with a new key every time.
+    if !room.latest_encryption_state().await?.is_encrypted() {
+        return send_cached(room, caption, mentions, reply, images).await;
+    }
 
+    if images.len() == 1 {
+        let (filename, image) = images.remove(0);
         let config = AttachmentConfig::new()
-            .info(AttachmentInfo::Image(BaseImageInfo {
-                height: info.height,
-                width: info.width,
-                size: info.size,
-                blurhash: None,
-                is_animated: Some(false),
-            }))
-            .caption(Some(TextMessageEventContent::html(
-                body.clone(),
-                formatted_body.clone(),
-            )))
-            .mentions(Some(Mentions::with_user_ids([ev.sender.clone()])))
-            .reply(Some(Reply {
-                event_id: ev.event_id.clone(),
-                enforce_thread: EnforceThread::MaybeThreaded,
-                add_mentions: AddMentions::Yes,
-            }));
+            .info(image_attachment_info(&image)?)
+            .caption(Some(caption))
+            .mentions(Some(mentions))
+            .reply(Some(reply));
 
         let content_type =
             Mime::from_str(file_format::FileFormat::from_bytes(&image).media_type())?;
 
-        room.send_attachment(filename, &content_type, image.to_vec(), config)
+        room.send_attachment(filename, &content_type, image, config)
             .await?;
     } else {
-        tracing::debug!("Not sending response because the requested illust is marked R-18.");
+        let mut gallery = GalleryConfig::new()
+            .caption(Some(caption))
+            .mentions(Some(mentions))
+            .reply(Some(reply));
+        for (filename, image) in images {
+            let attachment_info = image_attachment_info(&image)?;
+            let content_type =
+                Mime::from_str(file_format::FileFormat::from_bytes(&image).media_type())?;
+            gallery = gallery.add_item(GalleryItemInfo {
+                filename,
+                content_type,
+                data: image,
+                attachment_info,
+                caption: None,
+                thumbnail: None,
+            });
+        }
+
+        room.send_gallery(gallery).await?;
+    }
+
+    Ok(())
+}
+
+/// Sends the images as `reply`, reusing previous uploads with the same content.
+async fn send_cached(
+    room: &Room,
+    caption: TextMessageEventContent,
+    mentions: Mentions,
+    reply: Reply,
+    images: Vec<(String, Vec<u8>)>,
+) -> anyhow::Result<()> {
+    let client = room.client();
+
+    let mut items = Vec::with_capacity(images.len());
+    for (filename, image) in images {
+        let content_type =
+            Mime::from_str(file_format::FileFormat::from_bytes(&image).media_type())?;
+        let info = crate::matrix::imageinfo(&image)?;
+        let uri = crate::matrix::media::upload_cached(&client, &content_type, image).await?;
+        items.push(ImageMessageEventContent::plain(filename, uri).info(Some(Box::new(info))));
     }
 
+    let TextMessageEventContent {
+        body, formatted, ..
+    } = caption;
+    let msgtype = if items.len() == 1 {
+        let mut item = items.remove(0);
+        item.filename = Some(std::mem::replace(&mut item.body, body));
+        item.formatted = formatted;
+        MessageType::Image(item)
+    } else {
+        MessageType::Gallery(GalleryMessageEventContent::new(
+            body,
+            formatted,
+            items.into_iter().map(GalleryItemType::Image).collect(),
+        ))
+    };
+
+    // Threaded the same way as attachments sent with `reply`.
+    let content = room
+        .make_reply_event(
+            RoomMessageEventContentWithoutRelation::new(msgtype).add_mentions(mentions),
+            reply,
+        )
+        .await?;
+    room.send(content).await?;
+
     Ok(())
 }
+
+/// Returns the URL of the image in `image_size`, and the URL to fall back to if it's too large.
+fn select_url(
+    image_size: PixivImageSize,
+    small: &str,
+    regular: &str,
+    original: &str,
+) -> (String, Option<String>) {
+    match image_size {
+        PixivImageSize::Small => (small.to_string(), None),
+        PixivImageSize::Regular => (regular.to_string(), None),
+        PixivImageSize::Original => (original.to_string(), Some(regular.to_string())),
+    }
+}
+
+/// Downloads an image from Pixiv, returning its filename and content.
+pub(super) async fn download(
+    http: &reqwest::Client,
+    url: &str,
+) -> anyhow::Result<(String, Vec<u8>)> {
+    let (filename, response) = request(http, url).await?;
+    let image = response.bytes().await?.to_vec();
+
+    Ok((filename, image))
+}
+
+/// Downloads an image from Pixiv like [download],
+/// returning [None] if it's larger than `max_size` bytes.
+async fn download_limited(
+    http: &reqwest::Client,
+    url: &str,
+    max_size: u64,
+) -> anyhow::Result<Option<(String, Vec<u8>)>> {
+    let (filename, response) = request(http, url).await?;
+    let image = read_limited(response, max_size).await?;
+
+    Ok(image.map(|image| (filename, image)))
+}
+
+/// Reads the body of `response`, returning [None] as soon as it's larger than `max_size` bytes.
+async fn read_limited(
+    mut response: reqwest::Response,
+    max_size: u64,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    // Skip downloading the body at all if the size is already known.
+    if response
+        .content_length()
+        .is_some_and(|size| size > max_size)
+    {
+        return Ok(None);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if (body.len() + chunk.len()) as u64 > max_size {
+            return Ok(None);
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(Some(body))
+}
+
+async fn request(http: &reqwest::Client, url: &str) -> anyhow::Result<(String, reqwest::Response)> {
+    use url::Url;
+
+    let url = Url::parse(url)?;
+    let filename = url
+        .path_segments()
+        .and_then(|mut path| path.next_back())
+        .unwrap_or("file.png")
+        .to_string();
+
+    let response = http
+        .get(url)
+        .header(reqwest::header::REFERER, "https://www.pixiv.net")
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok((filename, response))
+}
+
+pub(super) fn image_attachment_info(image: &[u8]) -> anyhow::Result<AttachmentInfo> {
+    let info = crate::matrix::imageinfo(image)?;
+
+    Ok(AttachmentInfo::Image(BaseImageInfo {
+        height: info.height,
+        width: info.width,
+        size: info.size,
+        blurhash: None,
+        is_animated: Some(false),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use http_body::Frame;
+    use http_body_util::StreamBody;
+    use pretty_assertions::assert_eq;
+
+    /// Returns a response without a `Content-Length`, sending the body in `chunks`.
+    fn chunked(chunks: impl Iterator<Item = Bytes> + Send + Sync + 'static) -> reqwest::Response {
+        let frames = chunks.map(|chunk| Ok::<_, std::convert::Infallible>(Frame::data(chunk)));
+        let body = reqwest::Body::wrap(StreamBody::new(futures_util::stream::iter(frames)));
+
+        reqwest::Response::from(http::Response::new(body))
+    }
+
+    #[tokio::test]
+    async fn read_limited_chunked() {
+        let chunk = Bytes::from_static(&[0; 1024]);
+
+        let response = chunked(std::iter::repeat_n(chunk.clone(), 4));
+        let body = super::read_limited(response, 4096).await.unwrap();
+        assert_eq!(body.map(|body| body.len()), Some(4096));
+
+        // Reading stops once the limit is exceeded, so an endless body is fine.
+        let response = chunked(std::iter::repeat(chunk));
+        let body = super::read_limited(response, 4096).await.unwrap();
+        assert_eq!(body, None);
+    }
+}
@@ -1,8 +1,72 @@
+use std::collections::BTreeMap;
+
 use crate::config::TagTriggers;
+use crate::format::locale::Language;
 
+pub mod digest;
 pub mod illust;
+pub mod novel;
+pub mod ranking;
+pub mod search;
+pub mod session;
+pub mod ugoira;
+pub mod user;
+pub mod withheld;
 
 pub struct Context {
     pub r18: bool,
     pub tag_triggers: TagTriggers,
+    /// Maximum size of an image to upload, in bytes.
+    pub max_upload_size: u64,
+    /// Status of the Pixiv session, updated by [session::run].
+    pub session: parking_lot::RwLock<session::SessionStatus>,
+}
+
+/// Returns the translation of a tag in `language`.
+///
+/// Pixiv translates tags to the language of the request, and usually keys
+/// the translation with `en` regardless of it, so `en` is used when there is
+/// no translation keyed with the language.
+fn translated(translation: &BTreeMap<String, String>, language: Language) -> Option<&str> {
+    translation
+        .get(language.pixiv())
+        .or_else(|| translation.get("en"))
+        .map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::format::locale::Language;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn translated_prefers_the_language() {
+        let translation = BTreeMap::from([
+            ("en".to_string(), "Firefly".to_string()),
+            ("zh".to_string(), "流萤".to_string()),
+        ]);
+
+        assert_eq!(
+            super::translated(&translation, Language::ZhCn),
+            Some("流萤")
+        );
+        assert_eq!(
+            super::translated(&translation, Language::Ja),
+            Some("Firefly")
+        );
+        assert_eq!(super::translated(&BTreeMap::new(), Language::En), None);
+    }
+
+    #[test]
+    fn translated_falls_back_to_en() {
+        // A tag requested in Chinese, translated to Chinese but keyed with `en`.
+        let translation = BTreeMap::from([("en".to_string(), "流萤".to_string())]);
+
+        assert_eq!(
+            super::translated(&translation, Language::ZhCn),
+            Some("流萤")
+        );
+    }
 }
@@ -0,0 +1,142 @@
+use std::sync::LazyLock;
+
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+use pixiv_ajax_api::{
+    common::Restriction,
+    novel::{NovelInfo, NovelSeries},
+};
+use regex::Regex;
+
+use crate::format::locale::Language;
+
+/// How many characters of the text to include.
+const EXCERPT_LENGTH: usize = 200;
+
+/// Matches Pixiv novel markups, like `[newpage]` or `[[rb:漢字 > かんじ]]`.
+static MARKUP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\[\[(?:rb|jumpuri):\s*(?P<text>[^>\]]*?)\s*>[^\]]*\]\]|\[chapter:\s*(?P<chapter>[^\]]*)\]|\[(?:newpage|jump:\d+|pixivimage:[^\]]*|uploadedimage:[^\]]*)\]",
+    )
+    .unwrap()
+});
+
+pub struct Novel {
+    pub info: NovelInfo,
+    pub series: Option<NovelSeries>,
+}
+
+pub async fn request(
+    pixiv: &pixiv_ajax_api::PixivClient,
+    language: Language,
+    novel_id: u64,
+) -> anyhow::Result<Novel> {
+    let info = pixiv
+        .novel_info(novel_id)
+        .with_lang(language.pixiv())
+        .await?;
+    // The series is only used for the novel count, the novel is still shown without it.
+    let series = match &info.series_nav_data {
+        Some(nav) => pixiv
+            .novel_series(nav.series_id)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!("Failed to request series {id}: {e}", id = nav.series_id)
+            })
+            .ok(),
+        None => None,
+    };
+
+    Ok(Novel { info, series })
+}
+
+/// Formats the novel, returning [None] if it's R-18 and `send_r18` is false.
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: Novel,
+    send_r18: bool,
+) -> anyhow::Result<Option<RoomMessageEventContent>> {
+    if resp.info.restriction != Restriction::General && !send_r18 {
+        return Ok(None);
+    }
+
+    use crate::format::pixiv::novel::{Author, Context, Series, Tag};
+
+    let tags: Vec<_> = resp
+        .info
+        .tags
+        .tags
+        .iter()
+        .map(|tag| Tag {
+            original: &tag.tag,
+            translated: super::translated(&tag.translation, language),
+        })
+        .collect();
+    let excerpt = excerpt(&resp.info.content);
+
+    let context = Context {
+        id: resp.info.id,
+        title: &resp.info.title,
+        tags: &tags,
+        author: Author {
+            id: resp.info.user_id,
+            name: &resp.info.user_name,
+        },
+        series: resp.info.series_nav_data.as_ref().map(|nav| Series {
+            id: nav.series_id,
+            title: &nav.title,
+            order: nav.order,
+            count: resp
+                .series
+                .as_ref()
+                .map(|series| series.published_content_count),
+        }),
+        word_count: resp.info.word_count,
+        excerpt: Some(excerpt.as_str()).filter(|excerpt| !excerpt.is_empty()),
+    };
+    let body = crate::format::pixiv::novel::text::format(env, &context)?;
+    let html_body = crate::format::pixiv::novel::html::format(env, &context)?;
+
+    Ok(Some(RoomMessageEventContent::text_html(body, html_body)))
+}
+
+/// Returns the beginning of the text with markups removed, ending with `…` if it's cut.
+fn excerpt(content: &str) -> String {
+    let text = MARKUP.replace_all(content, |captures: &regex::Captures| {
+        captures
+            .name("text")
+            .or_else(|| captures.name("chapter"))
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default()
+    });
+    let text = text.trim();
+
+    let mut excerpt: String = text.chars().take(EXCERPT_LENGTH).collect();
+    if excerpt.len() < text.len() {
+        excerpt.push('…');
+    }
+
+    excerpt
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn excerpt_removes_markups() {
+        let content = "[chapter:第一章]\n[[rb:桜 > さくら]]が散る。[pixivimage:12345]\n[newpage]\n[[jumpuri:続き > https://example.com]]";
+
+        assert_eq!(super::excerpt(content), "第一章\n桜が散る。\n\n続き");
+    }
+
+    #[test]
+    fn excerpt_truncates() {
+        let content = "あ".repeat(super::EXCERPT_LENGTH + 1);
+
+        assert_eq!(
+            super::excerpt(&content),
+            format!("{}…", "あ".repeat(super::EXCERPT_LENGTH))
+        );
+    }
+}
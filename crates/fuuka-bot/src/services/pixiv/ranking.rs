@@ -0,0 +1,80 @@
+use futures_util::{StreamExt, TryStreamExt};
+use pixiv_ajax_api::ranking::RankingItem;
+
+use crate::config::PixivAIFilter;
+use crate::format::locale::Language;
+use crate::message::pixiv::{RankingContent, RankingMode};
+
+/// Maximum items allowed to be requested at once.
+pub const MAX_COUNT: usize = 50;
+
+/// Requests `count` items of the ranking, skipping the first `offset` items.
+///
+/// Items hidden by `ai_filter` are not counted, so `offset` applies after filtering.
+pub async fn request(
+    pixiv: &pixiv_ajax_api::PixivClient,
+    mode: RankingMode,
+    content: RankingContent,
+    date: Option<&str>,
+    offset: usize,
+    count: usize,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<Vec<RankingItem>> {
+    if count > MAX_COUNT {
+        anyhow::bail!("At most {MAX_COUNT} items can be requested!");
+    }
+
+    let items = pixiv
+        .ranking_stream(mode.into(), content.into(), date.map(ToString::to_string))
+        .try_filter(|item| std::future::ready(!ai_filter.hides(item.ai_type)))
+        .skip(offset)
+        .take(count)
+        .try_collect()
+        .await?;
+
+    Ok(items)
+}
+
+/// Formats the items, returning the plain text and HTML bodies.
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    items: &[RankingItem],
+    mode: RankingMode,
+    content: RankingContent,
+    date: Option<&str>,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<(String, String)> {
+    use crate::format::pixiv::ranking::{Author, Context, Item};
+
+    let tags: Vec<Vec<&str>> = items
+        .iter()
+        .map(|item| item.tags.iter().map(String::as_str).collect())
+        .collect();
+    let items: Vec<_> = items
+        .iter()
+        .zip(&tags)
+        .map(|(item, tags)| Item {
+            rank: item.rank,
+            id: item.illust_id,
+            title: &item.title,
+            tags,
+            author: Author {
+                id: item.user_id,
+                name: &item.user_name,
+            },
+            ai: ai_filter.labels(item.ai_type),
+        })
+        .collect();
+    let context = Context {
+        language,
+        mode: mode.name(),
+        content: content.name(),
+        date,
+        items: &items,
+    };
+    let body = crate::format::pixiv::ranking::text::format(env, &context)?;
+    let html_body = crate::format::pixiv::ranking::html::format(env, &context)?;
+
+    Ok((body, html_body))
+}
@@ -0,0 +1,83 @@
+use futures_util::{StreamExt, TryStreamExt};
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+use pixiv_ajax_api::search::{SearchItem, SearchMode, SearchOrder};
+
+use crate::config::PixivAIFilter;
+use crate::format::locale::Language;
+
+/// Maximum results allowed to be requested at once.
+pub const MAX_RESULTS: usize = 20;
+
+pub struct Search {
+    pub word: String,
+    pub items: Vec<SearchItem>,
+}
+
+/// Searches the newest `count` artworks with `word`, including R-18 ones only if `send_r18` is true.
+///
+/// Artworks hidden by `ai_filter` are not counted.
+pub async fn request(
+    pixiv: &pixiv_ajax_api::PixivClient,
+    word: String,
+    count: usize,
+    send_r18: bool,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<Search> {
+    if count > MAX_RESULTS {
+        anyhow::bail!("At most {MAX_RESULTS} results can be requested!");
+    }
+    let mode = if send_r18 {
+        SearchMode::All
+    } else {
+        SearchMode::Safe
+    };
+
+    let items = pixiv
+        .search_stream(&word, SearchOrder::Newest, mode)
+        .try_filter(|item| std::future::ready(!ai_filter.hides(item.ai_type)))
+        .take(count)
+        .try_collect()
+        .await?;
+
+    Ok(Search { word, items })
+}
+
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: Search,
+    ai_filter: PixivAIFilter,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::pixiv::search::{Author, Context, Item};
+    use url::Url;
+
+    let mut url = Url::parse("https://www.pixiv.net/tags/")?;
+    url.path_segments_mut()
+        .map_err(|_| anyhow::anyhow!("URL is a cannot-be-a-base!"))?
+        .pop_if_empty()
+        .extend([resp.word.as_str(), "artworks"]);
+
+    let items: Vec<_> = resp
+        .items
+        .iter()
+        .map(|item| Item {
+            id: item.id,
+            title: &item.title,
+            author: Author {
+                id: item.user_id,
+                name: &item.user_name,
+            },
+            ai: ai_filter.labels(item.ai_type),
+        })
+        .collect();
+    let context = Context {
+        language,
+        word: &resp.word,
+        url: url.as_str(),
+        items: &items,
+    };
+    let body = crate::format::pixiv::search::text::format(env, &context)?;
+    let html_body = crate::format::pixiv::search::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
@@ -0,0 +1,95 @@
+//! Checks if the Pixiv session is still logged in.
+use std::sync::Arc;
+use std::time::Duration;
+
+use matrix_sdk::ruma::{OwnedUserId, events::room::message::RoomMessageEventContent};
+use pixiv_ajax_api::PixivClient;
+
+use crate::format::locale::Messages;
+
+/// Status of the Pixiv session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionStatus {
+    /// Not checked yet.
+    #[default]
+    Unknown,
+    /// Logged in as the user ID.
+    LoggedIn(u64),
+    /// The token has expired.
+    Expired,
+}
+
+impl SessionStatus {
+    /// Renders the status with `messages`.
+    pub fn message(self, messages: &Messages) -> anyhow::Result<String> {
+        match self {
+            Self::Unknown => messages.message("pixiv_session_unknown", &[]),
+            Self::LoggedIn(user_id) => {
+                messages.message("pixiv_session_logged_in", &[user_id.into()])
+            }
+            Self::Expired => messages.message("pixiv_session_expired", &[]),
+        }
+    }
+}
+
+/// Checks the session at startup and then every `interval`,
+/// sending a DM to the admin user of `bot` when it expires.
+pub(crate) async fn run(
+    client: matrix_sdk::Client,
+    pixiv: Arc<PixivClient>,
+    context: Arc<super::Context>,
+    bot: crate::Context,
+    interval: Duration,
+) {
+    let mut interval = tokio::time::interval(interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let status = match pixiv.self_user_id().await {
+            Ok(Some(user_id)) => SessionStatus::LoggedIn(user_id),
+            Ok(None) => SessionStatus::Expired,
+            Err(e) => {
+                tracing::warn!("Failed to check the Pixiv session: {e:#}");
+                continue;
+            }
+        };
+
+        let previous = std::mem::replace(&mut *context.session.write(), status);
+        if status == previous {
+            continue;
+        }
+
+        match status {
+            SessionStatus::LoggedIn(user_id) => {
+                tracing::info!("Logged in to Pixiv as {user_id}");
+            }
+            SessionStatus::Expired => {
+                tracing::warn!("The Pixiv session has expired");
+                if let Some(admin) = &bot.admin_user
+                    && let Err(e) = notify(&client, &bot, admin).await
+                {
+                    tracing::warn!("Failed to notify the admin of the expired session: {e:#}");
+                }
+            }
+            SessionStatus::Unknown => {}
+        }
+    }
+}
+
+async fn notify(
+    client: &matrix_sdk::Client,
+    bot: &crate::Context,
+    admin: &OwnedUserId,
+) -> anyhow::Result<()> {
+    let room = match client.get_dm_room(admin).await? {
+        Some(room) => room,
+        None => client.create_dm(admin).await?,
+    };
+
+    let body = bot.message(room.room_id(), "pixiv_session_expired_notice", &[])?;
+    room.send(RoomMessageEventContent::text_plain(body)).await?;
+
+    Ok(())
+}
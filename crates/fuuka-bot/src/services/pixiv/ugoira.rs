@@ -0,0 +1,123 @@
+//! Converts ugoira frames into an animated GIF.
+use std::io::{Cursor, Read};
+
+use anyhow::Context;
+use image::{
+    Delay, Frame,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use pixiv_ajax_api::illust::UgoiraFrame;
+
+/// Encoding speed passed to the GIF encoder, in `1..=30`.
+///
+/// Higher is faster at the cost of the color quality.
+const GIF_SPEED: i32 = 10;
+
+/// Downloads the frame ZIP of an ugoira, returning the filename and the assembled GIF.
+pub async fn download(
+    pixiv: &pixiv_ajax_api::PixivClient,
+    http: &reqwest::Client,
+    illust_id: i32,
+) -> anyhow::Result<(String, Vec<u8>)> {
+    let meta = pixiv.illust_ugoira_meta(illust_id).await?;
+
+    let archive = http
+        .get(&meta.src)
+        .header(reqwest::header::REFERER, "https://www.pixiv.net")
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+
+    let image = tokio::task::spawn_blocking(move || assemble(&archive, &meta.frames)).await??;
+
+    Ok((format!("{illust_id}_ugoira.gif"), image))
+}
+
+/// Assembles the frames in the ZIP into an endlessly looping GIF, using the delays in `frames`.
+pub fn assemble(archive: &[u8], frames: &[UgoiraFrame]) -> anyhow::Result<Vec<u8>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(archive))?;
+    let mut output = Vec::new();
+
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut output, GIF_SPEED);
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        for frame in frames {
+            let mut data = Vec::new();
+            archive
+                .by_name(&frame.file)
+                .with_context(|| format!("Frame {} is missing in the archive", frame.file))?
+                .read_to_end(&mut data)?;
+            let image = image::load_from_memory(&data)?.into_rgba8();
+
+            encoder.encode_frame(Frame::from_parts(
+                image,
+                0,
+                0,
+                Delay::from_numer_denom_ms(frame.delay, 1),
+            ))?;
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use image::{AnimationDecoder, ImageFormat, RgbImage, codecs::gif::GifDecoder};
+    use pixiv_ajax_api::illust::UgoiraFrame;
+    use pretty_assertions::assert_eq;
+    use zip::write::SimpleFileOptions;
+
+    fn frame(file: &str, delay: u32) -> UgoiraFrame {
+        serde_json::from_value(serde_json::json!({ "file": file, "delay": delay })).unwrap()
+    }
+
+    #[test]
+    fn assemble_ok() {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, color) in [("000000.jpg", [255, 0, 0]), ("000001.jpg", [0, 0, 255])] {
+            let mut jpeg = Vec::new();
+            RgbImage::from_pixel(4, 4, image::Rgb(color))
+                .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+                .unwrap();
+            archive
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            archive.write_all(&jpeg).unwrap();
+        }
+        let archive = archive.finish().unwrap().into_inner();
+
+        let gif = super::assemble(
+            &archive,
+            &[frame("000000.jpg", 100), frame("000001.jpg", 250)],
+        )
+        .unwrap();
+
+        let frames = GifDecoder::new(Cursor::new(gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        let delays: Vec<_> = frames
+            .iter()
+            .map(|frame| frame.delay().numer_denom_ms())
+            .collect();
+        assert_eq!(delays, vec![(100, 1), (250, 1)]);
+        assert_eq!(frames[0].buffer().dimensions(), (4, 4));
+    }
+
+    #[test]
+    fn assemble_missing_frame() {
+        let archive = zip::ZipWriter::new(Cursor::new(Vec::new()))
+            .finish()
+            .unwrap()
+            .into_inner();
+
+        assert!(super::assemble(&archive, &[frame("000000.jpg", 100)]).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use matrix_sdk::ruma::events::room::message::RoomMessageEventContent;
+use pixiv_ajax_api::{
+    common::Restriction,
+    user::{UserInfo, UserProfile, UserWork},
+};
+
+use crate::format::locale::Language;
+
+/// How many latest works to include.
+const LATEST_WORKS: usize = 5;
+
+pub struct User {
+    pub info: UserInfo,
+    pub profile: UserProfile,
+    /// The latest illusts and manga, newest first.
+    pub works: Vec<UserWork>,
+}
+
+pub async fn request(pixiv: &pixiv_ajax_api::PixivClient, user_id: u64) -> anyhow::Result<User> {
+    let info = pixiv.user_info(user_id).await?;
+    let profile = pixiv.user_profile(user_id).await?;
+
+    // IDs are increasing, so the largest ones are the latest.
+    let mut ids: Vec<u64> = profile
+        .illusts
+        .iter()
+        .chain(profile.manga.iter())
+        .copied()
+        .collect();
+    ids.sort_unstable_by(|a, b| b.cmp(a));
+    ids.truncate(LATEST_WORKS);
+
+    let mut works = if ids.is_empty() {
+        Vec::new()
+    } else {
+        pixiv
+            .user_works(user_id, &ids)
+            .await
+            .map(|works| works.works)
+            .inspect_err(|e| tracing::warn!("Failed to request latest works of {user_id}: {e}"))
+            .unwrap_or_default()
+    };
+    works.sort_unstable_by(|a, b| b.id.cmp(&a.id));
+
+    Ok(User {
+        info,
+        profile,
+        works,
+    })
+}
+
+/// Formats the user, leaving out R-18 works if `send_r18` is false.
+pub fn format(
+    env: &minijinja::Environment,
+    language: Language,
+    resp: User,
+    send_r18: bool,
+) -> anyhow::Result<RoomMessageEventContent> {
+    use crate::format::pixiv::user::{Context, Work};
+
+    let works: Vec<_> = resp
+        .works
+        .iter()
+        .filter(|work| send_r18 || work.restriction == Restriction::General)
+        .map(|work| Work {
+            id: work.id,
+            title: &work.title,
+        })
+        .collect();
+    let context = Context {
+        language,
+        id: resp.info.user_id,
+        name: &resp.info.name,
+        comment: Some(resp.info.comment.as_str()).filter(|comment| !comment.is_empty()),
+        illusts_count: resp.profile.illusts.len(),
+        manga_count: resp.profile.manga.len(),
+        novels_count: resp.profile.novels.len(),
+        works: &works,
+    };
+    let body = crate::format::pixiv::user::text::format(env, &context)?;
+    let html_body = crate::format::pixiv::user::html::format(env, &context)?;
+
+    Ok(RoomMessageEventContent::text_html(body, html_body))
+}
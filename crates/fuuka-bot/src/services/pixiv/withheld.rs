@@ -0,0 +1,144 @@
+//! Pixelated previews of illusts withheld from a room.
+use std::io::Cursor;
+
+use image::{ImageFormat, imageops::FilterType};
+use matrix_sdk::{
+    Room,
+    attachment::{AttachmentConfig, AttachmentInfo, BaseImageInfo},
+    room::reply::{EnforceThread, Reply},
+    ruma::events::{
+        Mentions,
+        room::message::{AddMentions, OriginalRoomMessageEvent, TextMessageEventContent},
+    },
+};
+use pixiv_ajax_api::illust::IllustInfo;
+
+use crate::format::locale::Language;
+
+/// Number of blocks on the longer side of the pixelated preview.
+const BLOCKS: u32 = 12;
+
+/// Number of blurhash components on each axis.
+const BLURHASH_COMPONENTS: (u32, u32) = (4, 3);
+
+/// A pixelated preview image.
+pub struct Preview {
+    /// The preview in JPEG.
+    pub image: Vec<u8>,
+    pub blurhash: String,
+}
+
+/// Sends a pixelated thumbnail of the illust with its title marked as a spoiler,
+/// replying to `ev` with why the full image is withheld.
+pub async fn send(
+    ev: &OriginalRoomMessageEvent,
+    room: &Room,
+    http: &reqwest::Client,
+    env: &minijinja::Environment,
+    language: Language,
+    resp: &IllustInfo,
+) -> anyhow::Result<()> {
+    use crate::format::pixiv::withheld::Context;
+
+    let context = Context {
+        language,
+        id: resp.id,
+        title: &resp.title,
+        restriction: super::illust::restriction_name(resp.restriction),
+    };
+    let body = crate::format::pixiv::withheld::text::format(env, &context)?;
+    let html_body = crate::format::pixiv::withheld::html::format(env, &context)?;
+
+    let (_, thumbnail) = super::illust::download(http, &resp.urls.small).await?;
+    let preview = tokio::task::spawn_blocking(move || pixelate(&thumbnail)).await??;
+
+    let info = crate::matrix::imageinfo(&preview.image)?;
+    let config = AttachmentConfig::new()
+        .info(AttachmentInfo::Image(BaseImageInfo {
+            height: info.height,
+            width: info.width,
+            size: info.size,
+            blurhash: Some(preview.blurhash),
+            is_animated: Some(false),
+        }))
+        .caption(Some(TextMessageEventContent::html(body, html_body)))
+        .mentions(Some(Mentions::with_user_ids([ev.sender.clone()])))
+        .reply(Some(Reply {
+            event_id: ev.event_id.clone(),
+            enforce_thread: EnforceThread::MaybeThreaded,
+            add_mentions: AddMentions::Yes,
+        }));
+
+    room.send_attachment(
+        format!("{id}_preview.jpg", id = resp.id),
+        &mime::IMAGE_JPEG,
+        preview.image,
+        config,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Pixelates the image into [BLOCKS] blocks on the longer side, keeping its size.
+pub fn pixelate(image: &[u8]) -> anyhow::Result<Preview> {
+    let image = image::load_from_memory(image)?;
+    let (width, height) = (image.width(), image.height());
+    let scale = BLOCKS as f32 / width.max(height) as f32;
+    let small = image.resize_exact(
+        ((width as f32 * scale).round() as u32).max(1),
+        ((height as f32 * scale).round() as u32).max(1),
+        FilterType::Triangle,
+    );
+
+    let rgba = small.to_rgba8();
+    let blurhash = blurhash::encode(
+        BLURHASH_COMPONENTS.0,
+        BLURHASH_COMPONENTS.1,
+        rgba.width(),
+        rgba.height(),
+        rgba.as_raw(),
+    )?;
+
+    let pixelated = small
+        .resize_exact(width, height, FilterType::Nearest)
+        .into_rgb8();
+    let mut output = Vec::new();
+    pixelated.write_to(&mut Cursor::new(&mut output), ImageFormat::Jpeg)?;
+
+    Ok(Preview {
+        image: output,
+        blurhash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::{ImageFormat, RgbImage};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pixelate_ok() {
+        let image = RgbImage::from_fn(120, 60, |x, y| {
+            image::Rgb([(x * 2) as u8, (y * 4) as u8, 0])
+        });
+        let mut jpeg = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let preview = super::pixelate(&jpeg).unwrap();
+
+        // 1 size flag + 1 max AC + 4 DC + 2 for each AC component.
+        assert_eq!(preview.blurhash.len(), 6 + 2 * (4 * 3 - 1));
+
+        let decoded = image::load_from_memory(&preview.image).unwrap().into_rgb8();
+        assert_eq!(decoded.dimensions(), (120, 60));
+        // Each block of 10x10 pixels has a single color, besides JPEG artifacts.
+        let [r0, ..] = decoded.get_pixel(1, 1).0;
+        let [r1, ..] = decoded.get_pixel(8, 8).0;
+        assert!(r0.abs_diff(r1) < 8);
+    }
+}
@@ -14,22 +14,12 @@ use crate::matrix::streams::membership::MembershipStream;
 pub trait RoomMemberExt {
     /// Returns the display name or the user ID of the specified [RoomMember].
     fn name_or_id(&self) -> &str;
-    /// Constructs a HTML link of the specified [RoomMember], known as the mention "pill".
-    fn make_pill(&self) -> String;
 }
 
 impl RoomMemberExt for RoomMember {
     fn name_or_id(&self) -> &str {
         self.display_name().unwrap_or(self.user_id().as_str())
     }
-
-    fn make_pill(&self) -> String {
-        format!(
-            "<a href=\"{}\">@{}</a>",
-            self.user_id().matrix_to_uri(),
-            self.name()
-        )
-    }
 }
 
 /// Extensions to [MxcUri].
@@ -60,8 +50,8 @@ impl MxcUriExt for MxcUri {
 pub trait IllustTagsInfoExt {
     /// Check if we have the untranslated tag `tag`.
     fn has_tag(&self, tag: &str) -> bool;
-    /// Check if we have any of the untranslated tag `tags`.
-    fn has_any_tag(&self, tags: &[&str]) -> bool;
+    /// Check if any tag, either untranslated or translated, matches `regex`.
+    fn has_tag_matching(&self, regex: &regex::Regex) -> bool;
 }
 
 impl IllustTagsInfoExt for pixiv_ajax_api::illust::IllustTagsInfo {
@@ -69,8 +59,10 @@ impl IllustTagsInfoExt for pixiv_ajax_api::illust::IllustTagsInfo {
         self.tags.iter().any(|il| il.tag == tag)
     }
 
-    fn has_any_tag(&self, tags: &[&str]) -> bool {
-        self.tags.iter().any(|il| tags.iter().any(|t| il.tag == *t))
+    fn has_tag_matching(&self, regex: &regex::Regex) -> bool {
+        self.tags
+            .iter()
+            .any(|il| regex.is_match(&il.tag) || il.translation.values().any(|t| regex.is_match(t)))
     }
 }
 
@@ -92,7 +84,11 @@ pub trait RoomExt {
     where
         F: IntoFuture<Output = anyhow::Result<()>> + Send,
         <F as IntoFuture>::IntoFuture: Send;
-    fn send_requires_reply(&self) -> impl Future<Output = anyhow::Result<()>>;
+    /// Tells that the command requires a reply, in the language of the room.
+    fn send_requires_reply(
+        &self,
+        context: &crate::Context,
+    ) -> impl Future<Output = anyhow::Result<()>>;
 }
 
 impl RoomExt for matrix_sdk::Room {
@@ -156,11 +152,9 @@ impl RoomExt for matrix_sdk::Room {
         Ok(())
     }
 
-    async fn send_requires_reply(&self) -> anyhow::Result<()> {
-        self.send(RoomMessageEventContent::text_plain(
-            "Replying to a event is required for this command.",
-        ))
-        .await?;
+    async fn send_requires_reply(&self, context: &crate::Context) -> anyhow::Result<()> {
+        let body = context.message(self.room_id(), "requires_reply", &[])?;
+        self.send(RoomMessageEventContent::text_plain(body)).await?;
         Ok(())
     }
 }
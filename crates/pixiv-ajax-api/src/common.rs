@@ -37,10 +37,11 @@ impl Serialize for Restriction {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum AIType {
+    #[default]
     Unknown = 0,
     NotAI = 1,
     AI = 2,
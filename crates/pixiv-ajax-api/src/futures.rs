@@ -39,11 +39,11 @@ impl<'a, T: DeserializeOwned> IntoFuture for GetRequest<'a, T> {
         Box::pin(async move {
             let path_and_query = path_and_query?;
 
-            let query = match path_and_query.query() {
-                Some(query) => lang
-                    .map(|lang| format!("{query}&lang={lang}"))
-                    .unwrap_or_default(),
-                None => lang.map(|lang| format!("lang={lang}")).unwrap_or_default(),
+            let query = match (path_and_query.query(), lang) {
+                (Some(query), Some(lang)) => format!("{query}&lang={lang}"),
+                (Some(query), None) => query.to_string(),
+                (None, Some(lang)) => format!("lang={lang}"),
+                (None, None) => String::new(),
             };
             let path_and_query = PathAndQuery::from_maybe_shared(format!(
                 "{path}?{query}",
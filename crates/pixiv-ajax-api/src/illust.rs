@@ -114,6 +114,57 @@ pub struct IllustImageUrls {
     // TODO: Thumbs
 }
 
+/// A page of an illust, returned by `/ajax/illust/{id}/pages`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct IllustPage {
+    /// The URLs avaliable in the page.
+    pub urls: IllustPageUrls,
+    /// The width of the page.
+    pub width: u64,
+    /// The height of the page.
+    pub height: u64,
+}
+
+/// The URLs avaliable in a page of an illust.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct IllustPageUrls {
+    /// The thumbnail variant URL of the page.
+    pub thumb_mini: String,
+    /// The small variant URL of the page.
+    pub small: String,
+    /// The medium variant URL of the page.
+    pub regular: String,
+    /// The original variant URL of the page.
+    pub original: String,
+}
+
+/// The metadata of an ugoira, returned by `/ajax/illust/{id}/ugoira_meta`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UgoiraMeta {
+    /// The URL of the ZIP containing the frames in the medium size.
+    pub src: String,
+    /// The URL of the ZIP containing the frames in the original size.
+    pub original_src: String,
+    /// The MIME type of the frames.
+    pub mime_type: String,
+    /// The frames in the ZIP, in order.
+    pub frames: Vec<UgoiraFrame>,
+}
+
+/// A frame of an ugoira.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct UgoiraFrame {
+    /// The filename of the frame in the ZIP.
+    pub file: String,
+    /// How long the frame is shown in milliseconds.
+    pub delay: u32,
+}
+
 /// Illust tag base info.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
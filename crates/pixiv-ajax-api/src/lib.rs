@@ -1,8 +1,11 @@
 pub mod common;
 pub mod futures;
 pub mod illust;
+pub mod novel;
 pub mod ranking;
+pub mod search;
 mod serde;
+pub mod user;
 
 use std::{marker::PhantomData, str::FromStr};
 
@@ -16,8 +19,11 @@ use tower::{BoxError, Service, ServiceExt, buffer::Buffer, util::BoxService};
 
 use crate::{
     futures::GetRequest,
-    illust::IllustInfo,
+    illust::{IllustInfo, IllustPage, UgoiraMeta},
+    novel::{NovelInfo, NovelSeries},
     ranking::{Ranking, RankingContent, RankingItem, RankingMode},
+    search::{SearchItem, SearchMode, SearchOrder, SearchResult},
+    user::{UserInfo, UserProfile, UserWorks},
 };
 
 /// Holds a date time raw string.
@@ -262,6 +268,137 @@ impl PixivClient {
         }
     }
 
+    /// Get all pages of an illust.
+    pub fn illust_pages(&self, illust_id: i32) -> GetRequest<'_, Vec<IllustPage>> {
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/illust/{illust_id}/pages"),
+                None as Option<&()>,
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/artworks/{illust_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the ugoira metadata of an illust.
+    pub fn illust_ugoira_meta(&self, illust_id: i32) -> GetRequest<'_, UgoiraMeta> {
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/illust/{illust_id}/ugoira_meta"),
+                None as Option<&()>,
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/artworks/{illust_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the info of a novel, including the full text.
+    pub fn novel_info(&self, novel_id: u64) -> GetRequest<'_, NovelInfo> {
+        GetRequest {
+            client: self,
+            path_and_query: self
+                .parameterized_uri(format!("/ajax/novel/{novel_id}"), None as Option<&()>),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/novel/show.php?id={novel_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the info of a novel series.
+    pub fn novel_series(&self, series_id: u64) -> GetRequest<'_, NovelSeries> {
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/novel/series/{series_id}"),
+                None as Option<&()>,
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/novel/series/{series_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the info of an user, including the bio.
+    pub fn user_info(&self, user_id: u64) -> GetRequest<'_, UserInfo> {
+        GetRequest {
+            client: self,
+            path_and_query: self
+                .parameterized_uri(format!("/ajax/user/{user_id}"), Some(&[("full", "1")])),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/users/{user_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the IDs of all works of an user.
+    pub fn user_profile(&self, user_id: u64) -> GetRequest<'_, UserProfile> {
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/user/{user_id}/profile/all"),
+                None as Option<&()>,
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/users/{user_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Get the illusts and manga of an user by their IDs.
+    pub fn user_works(&self, user_id: u64, ids: &[u64]) -> GetRequest<'_, UserWorks> {
+        let parameters: Vec<_> = ids
+            .iter()
+            .map(|id| ("ids[]", id.to_string()))
+            .chain([
+                ("work_category", "illustManga".to_string()),
+                ("is_first_page", "0".to_string()),
+            ])
+            .collect();
+
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/user/{user_id}/profile/illusts"),
+                Some(&parameters),
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/users/{user_id}"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
     /// Get the User ID of the logged in user.
     pub async fn self_user_id(&self) -> Result<Option<u64>> {
         let resp = self._get(BASE_URL).await?;
@@ -272,6 +409,76 @@ impl PixivClient {
             .and_then(|value| <u64 as FromStr>::from_str(value).ok()))
     }
 
+    /// Search illusts and manga by tags, separated by spaces.
+    pub fn search(
+        &self,
+        word: &str,
+        order: SearchOrder,
+        mode: SearchMode,
+        page: u32,
+    ) -> GetRequest<'_, SearchResult> {
+        use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+
+        let order = match order {
+            SearchOrder::Newest => "date_d",
+            SearchOrder::Oldest => "date",
+            SearchOrder::Popular => "popular_d",
+        };
+        let mode = match mode {
+            SearchMode::All => "all",
+            SearchMode::Safe => "safe",
+            SearchMode::R18 => "r18",
+        };
+        let encoded = utf8_percent_encode(word, NON_ALPHANUMERIC).to_string();
+
+        GetRequest {
+            client: self,
+            path_and_query: self.parameterized_uri(
+                format!("/ajax/search/artworks/{encoded}"),
+                Some(&[
+                    ("word", word),
+                    ("order", order),
+                    ("mode", mode),
+                    ("p", &page.to_string()),
+                    ("s_mode", "s_tag"),
+                    ("type", "all"),
+                ]),
+            ),
+            lang: None,
+            referrer: HeaderValue::from_maybe_shared(format!(
+                "https://www.pixiv.net/tags/{encoded}/artworks"
+            ))
+            .map_err(Into::<http::Error>::into)
+            .map_err(Into::into),
+            _type: PhantomData,
+        }
+    }
+
+    /// Search illusts and manga by tags as a series of stream, fetching more pages as needed.
+    pub fn search_stream<'a>(
+        &'a self,
+        word: &'a str,
+        order: SearchOrder,
+        mode: SearchMode,
+    ) -> impl Stream<Item = Result<SearchItem>> + 'a {
+        async_stream::try_stream! {
+            let mut page = 1;
+            loop {
+                let result = self.search(word, order, mode, page).await?.illust_manga;
+                if result.data.is_empty() {
+                    break;
+                }
+                for item in result.data {
+                    yield item;
+                }
+                if page >= result.last_page {
+                    break;
+                }
+                page += 1;
+            }
+        }
+    }
+
     /// Get the Pixiv ranking.
     pub async fn ranking(
         &self,
@@ -283,22 +490,41 @@ impl PixivClient {
         self._ranking(mode, content, &date, page).await
     }
 
-    /// Get the Pixiv ranking as a series of stream.
-    pub async fn ranking_stream(
+    /// Get the Pixiv ranking as a series of stream, fetching more pages as needed.
+    pub fn ranking_stream(
         &self,
         mode: RankingMode,
         content: RankingContent,
         date: Option<String>,
+    ) -> impl Stream<Item = Result<RankingItem>> + '_ {
+        self.ranking_stream_with_limit(mode, content, date, usize::MAX)
+    }
+
+    /// Get at most `limit` items of the Pixiv ranking as a series of stream.
+    ///
+    /// No more pages are requested once `limit` items are yielded.
+    pub fn ranking_stream_with_limit(
+        &self,
+        mode: RankingMode,
+        content: RankingContent,
+        date: Option<String>,
+        limit: usize,
     ) -> impl Stream<Item = Result<RankingItem>> + '_ {
         async_stream::try_stream! {
-            let first = self._ranking(mode, content, &date, None).await?;
-            for content in first.contents {
-                yield content;
-            }
-            while let Some(next) = first.next {
-                let result = self._ranking(mode, content, &date, Some(next)).await?;
-                for content in result.contents {
-                    yield content;
+            let mut remaining = limit;
+            let mut page = 1;
+            while remaining > 0 {
+                let result = self
+                    ._ranking(mode, content, &date, (page > 1).then_some(page))
+                    .await?;
+                for item in result.contents.into_iter().take(remaining) {
+                    remaining -= 1;
+                    yield item;
+                }
+                match result.next {
+                    // Guard against a page pointing back to itself or a previous one.
+                    Some(next) if next > page => page = next,
+                    _ => break,
                 }
             }
         }
@@ -396,3 +622,167 @@ pub enum Error {
     #[error(transparent)]
     Other(#[from] BoxError),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::{Arc, Mutex},
+    };
+
+    use bytes::Bytes;
+    use futures_util::TryStreamExt;
+    use http_body_util::BodyExt;
+    use pretty_assertions::assert_eq;
+    use secrecy::SecretString;
+
+    use crate::{
+        PixivClient,
+        ranking::{RankingContent, RankingMode},
+    };
+
+    /// Builds a canned ranking page with `count` items, starting from `first_id`.
+    ///
+    /// The rank of each item is the same as its ID.
+    fn ranking_page(first_id: u64, count: u64, next: Option<u32>) -> String {
+        let contents: Vec<_> = (first_id..first_id + count)
+            .map(|illust_id| {
+                serde_json::json!({
+                    "rank": illust_id,
+                    "title": format!("Illust {illust_id}"),
+                    "tags": ["tag"],
+                    "user_name": "user",
+                    "profile_img": "https://i.pximg.net/user.jpg",
+                    "url": format!("https://i.pximg.net/{illust_id}.jpg"),
+                    "illust_id": illust_id,
+                    "user_id": 1,
+                    "width": 100,
+                    "height": 100,
+                    "view_count": 1000,
+                })
+            })
+            .collect();
+        let next = next.map_or(serde_json::json!(false), Into::into);
+
+        serde_json::json!({ "contents": contents, "prev": false, "next": next }).to_string()
+    }
+
+    /// Returns a client backed by a mock service which responds with `pages` in order,
+    /// and the list of requested URIs.
+    fn mock_client(pages: Vec<String>) -> (PixivClient, Arc<Mutex<Vec<String>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let pages = Arc::new(Mutex::new(pages.into_iter()));
+
+        let service = tower::service_fn({
+            let requests = requests.clone();
+            move |request: http::Request<crate::BoxBody>| {
+                requests.lock().unwrap().push(request.uri().to_string());
+                let page = pages
+                    .lock()
+                    .unwrap()
+                    .next()
+                    .expect("No more pages were expected to be requested");
+
+                async move {
+                    let body = http_body_util::Full::new(Bytes::from(page))
+                        .map_err(|never| match never {})
+                        .boxed();
+                    Ok::<_, Infallible>(http::Response::new(body))
+                }
+            }
+        });
+
+        (
+            PixivClient::new(service, SecretString::from("token")),
+            requests,
+        )
+    }
+
+    #[tokio::test]
+    async fn ranking_stream_follows_next() {
+        let (client, requests) = mock_client(vec![
+            ranking_page(1, 2, Some(2)),
+            ranking_page(3, 2, Some(3)),
+            ranking_page(5, 1, None),
+        ]);
+
+        let ids: Vec<_> = client
+            .ranking_stream(RankingMode::Daily, RankingContent::Illust, None)
+            .map_ok(|item| (item.rank, item.illust_id))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)]);
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "https://www.pixiv.net/ranking.php?format=json&mode=daily&content=illust",
+                "https://www.pixiv.net/ranking.php?format=json&mode=daily&content=illust&p=2",
+                "https://www.pixiv.net/ranking.php?format=json&mode=daily&content=illust&p=3",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn ranking_stream_stops_on_non_advancing_next() {
+        let (client, requests) = mock_client(vec![
+            ranking_page(1, 2, Some(2)),
+            ranking_page(3, 2, Some(2)),
+        ]);
+
+        let ids: Vec<_> = client
+            .ranking_stream(
+                RankingMode::Weekly,
+                RankingContent::All,
+                Some("20260101".to_string()),
+            )
+            .map_ok(|item| item.illust_id)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "https://www.pixiv.net/ranking.php?format=json&mode=weekly&date=20260101",
+                "https://www.pixiv.net/ranking.php?format=json&mode=weekly&p=2&date=20260101",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn ranking_stream_with_limit() {
+        let (client, requests) = mock_client(vec![
+            ranking_page(1, 2, Some(2)),
+            ranking_page(3, 2, Some(3)),
+            ranking_page(5, 2, None),
+        ]);
+
+        let ids: Vec<_> = client
+            .ranking_stream_with_limit(RankingMode::Daily, RankingContent::Manga, None, 3)
+            .map_ok(|item| item.illust_id)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn ranking_stream_with_zero_limit() {
+        let (client, requests) = mock_client(vec![]);
+
+        let ids: Vec<_> = client
+            .ranking_stream_with_limit(RankingMode::Daily, RankingContent::Illust, None, 0)
+            .map_ok(|item| item.illust_id)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(ids.is_empty());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
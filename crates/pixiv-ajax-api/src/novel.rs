@@ -0,0 +1,98 @@
+//! Types dealing with novels.
+use serde::{Deserialize, Serialize};
+
+use crate::common::{AIType, Restriction};
+use crate::illust::IllustTagsInfo;
+
+/// Novel info, returned by `/ajax/novel/{id}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NovelInfo {
+    /// The ID of the novel.
+    #[serde(with = "crate::serde::from_str")]
+    pub id: u64,
+    /// The title of the novel.
+    pub title: String,
+    /// The description of the novel in HTML format.
+    pub description: String,
+    /// The full text of the novel.
+    pub content: String,
+    /// The URL of the cover.
+    pub cover_url: String,
+    /// The date the novel is created.
+    pub create_date: crate::DateTime,
+    /// The date the novel is uploaded.
+    pub upload_date: crate::DateTime,
+    /// The restriction type for the novel.
+    #[serde(rename = "xRestrict")]
+    pub restriction: Restriction,
+    /// Whether this novel is generated with AI.
+    pub ai_type: AIType,
+    /// The tags infomation of the novel.
+    pub tags: IllustTagsInfo,
+    /// The User ID of the author.
+    #[serde(with = "crate::serde::from_str")]
+    pub user_id: u64,
+    /// The name of the author.
+    pub user_name: String,
+    /// How many words the novel have.
+    pub word_count: u64,
+    /// How many characters the novel have.
+    pub character_count: u64,
+    /// Estimated reading time in seconds.
+    pub reading_time: u64,
+    /// How many bookmarks the novel have.
+    pub bookmark_count: u64,
+    /// How many likes the novel have.
+    pub like_count: u64,
+    /// How many views the novel have.
+    pub view_count: u64,
+    /// Whether this novel is original work.
+    #[serde(rename = "isOriginal")]
+    pub original: bool,
+    /// The series this novel belongs to.
+    #[serde(default)]
+    pub series_nav_data: Option<NovelSeriesNav>,
+}
+
+/// The position of a novel in its series.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NovelSeriesNav {
+    /// The ID of the series.
+    pub series_id: u64,
+    /// The title of the series.
+    pub title: String,
+    /// The position of this novel in the series, starting from 1.
+    pub order: u64,
+}
+
+/// Novel series info, returned by `/ajax/novel/series/{id}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NovelSeries {
+    /// The ID of the series.
+    #[serde(with = "crate::serde::from_str")]
+    pub id: u64,
+    /// The title of the series.
+    pub title: String,
+    /// The caption of the series.
+    pub caption: String,
+    /// The User ID of the author.
+    #[serde(with = "crate::serde::from_str")]
+    pub user_id: u64,
+    /// The name of the author.
+    pub user_name: String,
+    /// The restriction type for the series.
+    #[serde(rename = "xRestrict")]
+    pub restriction: Restriction,
+    /// How many novels are published in the series.
+    pub published_content_count: u64,
+    /// How many words are published in the series.
+    pub published_total_word_count: u64,
+    /// The tags of the series.
+    pub tags: Vec<String>,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::common::AIType;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -15,15 +17,21 @@ pub struct Ranking {
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub struct RankingItem {
+    /// The rank of the item, starting from 1.
+    pub rank: u64,
     pub title: String,
     pub tags: Vec<String>,
     pub user_name: String,
     pub profile_img: String,
+    /// The URL of the thumbnail.
+    pub url: String,
     pub illust_id: u64,
     pub user_id: u64,
     pub width: u64,
     pub height: u64,
     pub view_count: u64,
+    #[serde(rename = "illust_ai_type", default)]
+    pub ai_type: AIType,
 }
 
 /// The ranking mode.
@@ -0,0 +1,108 @@
+//! Types dealing with searching.
+use serde::{Deserialize, Serialize};
+
+use crate::common::{AIType, Restriction};
+use crate::illust::IllustType;
+
+/// Search result of illusts and manga, returned by `/ajax/search/artworks/{word}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchResult {
+    /// The matched illusts and manga.
+    pub illust_manga: SearchArtworks,
+}
+
+/// A page of matched artworks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchArtworks {
+    /// The artworks in this page, with ad placeholders removed.
+    #[serde(deserialize_with = "deserialize_without_ads")]
+    pub data: Vec<SearchItem>,
+    /// How many artworks matched.
+    pub total: u64,
+    /// The number of the last page.
+    pub last_page: u32,
+}
+
+/// A matched artwork.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchItem {
+    /// The ID of the artwork.
+    #[serde(with = "crate::serde::from_str")]
+    pub id: u64,
+    /// The title of the artwork.
+    pub title: String,
+    /// The type of the artwork.
+    pub illust_type: IllustType,
+    /// The restriction type for the artwork.
+    #[serde(rename = "xRestrict")]
+    pub restriction: Restriction,
+    /// Whether this artwork is generated with AI.
+    pub ai_type: AIType,
+    /// The untranslated tags of the artwork.
+    pub tags: Vec<String>,
+    /// The User ID of the author.
+    #[serde(with = "crate::serde::from_str")]
+    pub user_id: u64,
+    /// The name of the author.
+    pub user_name: String,
+    /// How many pages the artwork have.
+    pub page_count: u64,
+    /// The date the artwork is created.
+    pub create_date: crate::DateTime,
+}
+
+/// The order of search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchOrder {
+    /// Newest first.
+    #[default]
+    Newest,
+    /// Oldest first.
+    Oldest,
+    /// Most popular first, requires Pixiv Premium.
+    Popular,
+}
+
+/// Which artworks to include in search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMode {
+    /// Both all-ages and R-18 artworks.
+    All,
+    /// All-ages artworks only.
+    #[default]
+    Safe,
+    /// R-18 artworks only.
+    R18,
+}
+
+fn deserialize_without_ads<'de, D>(deserializer: D) -> Result<Vec<SearchItem>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Item {
+        Artwork(SearchItem),
+        #[allow(unused)]
+        Ad {
+            #[serde(rename = "isAdContainer")]
+            is_ad_container: serde_bool::True,
+        },
+    }
+
+    Vec::<Item>::deserialize(deserializer).map(|items| {
+        items
+            .into_iter()
+            .filter_map(|item| match item {
+                Item::Artwork(item) => Some(item),
+                Item::Ad { .. } => None,
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,22 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes the values of a map, also accepting an empty array for an empty map.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Inner<T> {
+        BTreeMap(BTreeMap<String, T>),
+        Vec(Vec<T>),
+    }
+
+    Inner::deserialize(deserializer).map(|inner| match inner {
+        Inner::BTreeMap(map) => map.into_values().collect(),
+        Inner::Vec(vec) => vec,
+    })
+}
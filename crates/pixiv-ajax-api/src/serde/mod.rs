@@ -1,3 +1,4 @@
 pub(crate) mod dict_key_as_vec;
+pub(crate) mod dict_values_as_vec;
 pub(crate) mod false_is_none;
 pub(crate) mod from_str;
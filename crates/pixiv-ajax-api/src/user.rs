@@ -0,0 +1,76 @@
+//! Types dealing with users.
+use serde::{Deserialize, Serialize};
+
+use crate::common::Restriction;
+use crate::illust::IllustType;
+
+/// User info, returned by `/ajax/user/{id}`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UserInfo {
+    /// The ID of the user.
+    #[serde(with = "crate::serde::from_str")]
+    pub user_id: u64,
+    /// The name of the user.
+    pub name: String,
+    /// The URL of the avatar.
+    pub image: String,
+    /// The URL of the avatar in a bigger size.
+    pub image_big: String,
+    /// Whether the user has Pixiv Premium.
+    pub premium: bool,
+    /// Whether the account holder follows the user.
+    pub is_followed: bool,
+    /// The bio of the user in plain text.
+    #[serde(default)]
+    pub comment: String,
+    /// The website of the user.
+    #[serde(default)]
+    pub webpage: Option<String>,
+}
+
+/// All works of an user, returned by `/ajax/user/{id}/profile/all`.
+///
+/// The IDs are not in any particular order.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct UserProfile {
+    /// IDs of all illusts.
+    #[serde(deserialize_with = "crate::serde::dict_key_as_vec::deserialize")]
+    pub illusts: Vec<u64>,
+    /// IDs of all manga.
+    #[serde(deserialize_with = "crate::serde::dict_key_as_vec::deserialize")]
+    pub manga: Vec<u64>,
+    /// IDs of all novels.
+    #[serde(deserialize_with = "crate::serde::dict_key_as_vec::deserialize")]
+    pub novels: Vec<u64>,
+}
+
+/// Works of an user, returned by `/ajax/user/{id}/profile/illusts`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct UserWorks {
+    /// The requested works, in no particular order.
+    #[serde(deserialize_with = "crate::serde::dict_values_as_vec::deserialize")]
+    pub works: Vec<UserWork>,
+}
+
+/// A work in [UserWorks].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UserWork {
+    /// The ID of the work.
+    #[serde(with = "crate::serde::from_str")]
+    pub id: u64,
+    /// The title of the work.
+    pub title: String,
+    /// The type of the work.
+    pub illust_type: IllustType,
+    /// The restriction type for the work.
+    #[serde(rename = "xRestrict")]
+    pub restriction: Restriction,
+    /// The date the work is created.
+    pub create_date: crate::DateTime,
+}